@@ -0,0 +1,278 @@
+//! Derive macros for the [ownref](https://docs.rs/ownref/) crate.
+//!
+//! This crate is re-exported by `ownref` with the `derive` feature.
+
+use proc_macro2::{Span, TokenStream};
+use quote::{format_ident, quote};
+use syn::{
+    ext::IdentExt, parse_macro_input, parse_quote, Data, DeriveInput, Field, Fields, GenericParam,
+    Ident, Index, Lifetime, Member, Type, Visibility,
+};
+
+/// Generate named projections for an owner type.
+///
+/// For a type `Owner`, it generates the trait `OwnerProject`, which is
+/// implemented for every smart reference implementing
+/// `ownref::Project<Target = Owner>`, including `ArcRef`, `BoxRef`,
+/// `ArcOwned` of a reference and `BoxOwned` of a mutable reference.
+///
+/// - For a struct, each field `name` gets a method `name()` returning the
+///   projection to the field. Fields of a tuple struct are named `_0`, `_1`, ...
+///   Private fields are skipped if the struct is not private.
+/// - For an enum, each variant `Variant` with exactly one field gets a method
+///   `variant()` returning an optional projection to the field. Variants with
+///   multiple fields get a method `variant_field()` per field.
+#[proc_macro_derive(Project)]
+pub fn project_derive(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    match project(input) {
+        Ok(output) => output.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+/// A generated projection method.
+struct Method {
+    name: Ident,
+    ty: Type,
+    body: TokenStream,
+}
+
+fn project(input: DeriveInput) -> syn::Result<TokenStream> {
+    let DeriveInput {
+        vis,
+        ident,
+        generics,
+        data,
+        ..
+    } = &input;
+
+    let lifetime = Lifetime::new("'__ownref", Span::call_site());
+    let param = format_ident!("__P");
+    let trait_name = format_ident!("{}Project", ident);
+    let (_, ty_generics, _) = generics.split_for_impl();
+    let owner_ty: Type = parse_quote! { #ident #ty_generics };
+
+    let methods = match data {
+        Data::Struct(data) => data
+            .fields
+            .iter()
+            .enumerate()
+            .filter(|(_, field)| is_visible(vis, &field.vis))
+            .map(|(index, field)| {
+                let (name, member) = field_name(index, field, None);
+                let ty = field.ty.clone();
+                let body = quote! {
+                    ::ownref::Project::project(
+                        self,
+                        |owner: &#lifetime #owner_ty| &owner.#member,
+                        |owner: &#lifetime mut #owner_ty| &mut owner.#member,
+                    )
+                };
+
+                Method { name, ty, body }
+            })
+            .collect::<Vec<_>>(),
+        Data::Enum(data) => data
+            .variants
+            .iter()
+            .flat_map(|variant| {
+                let variant_ident = &variant.ident;
+                let prefix = snake_case(&variant_ident.to_string());
+                let single = variant.fields.len() == 1;
+                let owner_ty = &owner_ty;
+                let lifetime = &lifetime;
+
+                variant
+                    .fields
+                    .iter()
+                    .enumerate()
+                    .map(move |(index, field)| {
+                        let (name, member) = if single {
+                            let (_, member) = field_name(index, field, None);
+                            (ident_from(&prefix), member)
+                        } else {
+                            field_name(index, field, Some(&prefix))
+                        };
+                        let pattern = match &variant.fields {
+                            Fields::Named(_) => {
+                                quote! { #ident::#variant_ident { #member: field, .. } }
+                            }
+                            _ => {
+                                let skips = (0..index).map(|_| quote! { _ });
+                                quote! { #ident::#variant_ident(#(#skips,)* field, ..) }
+                            }
+                        };
+                        let ty = field.ty.clone();
+                        let body = quote! {
+                            ::ownref::Project::filter_project(
+                                self,
+                                |owner: &#lifetime #owner_ty| match owner {
+                                    #pattern => Some(field),
+                                    #[allow(unreachable_patterns)]
+                                    _ => None,
+                                },
+                                |owner: &#lifetime mut #owner_ty| match owner {
+                                    #pattern => Some(field),
+                                    #[allow(unreachable_patterns)]
+                                    _ => None,
+                                },
+                            )
+                        };
+
+                        Method { name, ty, body }
+                    })
+            })
+            .collect::<Vec<_>>(),
+        Data::Union(_) => {
+            return Err(syn::Error::new_spanned(
+                ident,
+                "Project cannot be derived for unions",
+            ))
+        }
+    };
+    let is_enum = matches!(data, Data::Enum(_));
+
+    let declarations = methods.iter().map(|Method { name, ty, .. }| {
+        if is_enum {
+            quote! {
+                fn #name(self) -> Option<Self::Output<#ty>>
+                where
+                    #ty: #lifetime;
+            }
+        } else {
+            quote! {
+                fn #name(self) -> Self::Output<#ty>
+                where
+                    #ty: #lifetime;
+            }
+        }
+    });
+    let definitions = methods.iter().map(|Method { name, ty, body }| {
+        if is_enum {
+            quote! {
+                fn #name(self) -> Option<Self::Output<#ty>>
+                where
+                    #ty: #lifetime,
+                {
+                    #body
+                }
+            }
+        } else {
+            quote! {
+                fn #name(self) -> Self::Output<#ty>
+                where
+                    #ty: #lifetime,
+                {
+                    #body
+                }
+            }
+        }
+    });
+
+    // generics of the trait: the projection lifetime followed by the owner generics
+    let mut trait_generics = generics.clone();
+    trait_generics.params.insert(0, parse_quote! { #lifetime });
+    trait_generics
+        .make_where_clause()
+        .predicates
+        .push(parse_quote! { #owner_ty: #lifetime });
+    let (_, trait_ty_generics, where_clause) = trait_generics.split_for_impl();
+    let trait_params = trait_generics.params.iter().map(strip_default);
+
+    let mut impl_generics = trait_generics.clone();
+    impl_generics.params.push(parse_quote! { #param });
+    impl_generics
+        .make_where_clause()
+        .predicates
+        .push(parse_quote! { #param: ::ownref::Project<#lifetime, Target = #owner_ty> });
+    let (impl_impl_generics, _, impl_where_clause) = impl_generics.split_for_impl();
+
+    let doc = format!("Named projections of [{}].", ident);
+
+    Ok(quote! {
+        #[doc = #doc]
+        #vis trait #trait_name<#(#trait_params),*>:
+            ::ownref::Project<#lifetime, Target = #owner_ty>
+        #where_clause
+        {
+            #(#declarations)*
+        }
+
+        impl #impl_impl_generics #trait_name #trait_ty_generics for #param
+        #impl_where_clause
+        {
+            #(#definitions)*
+        }
+    })
+}
+
+/// Get the method name and the member of a field.
+fn field_name(index: usize, field: &Field, prefix: Option<&str>) -> (Ident, Member) {
+    match &field.ident {
+        Some(ident) => {
+            let name = match prefix {
+                Some(prefix) => ident_from(&format!("{}_{}", prefix, ident.unraw())),
+                None => ident.clone(),
+            };
+            (name, Member::Named(ident.clone()))
+        }
+        None => {
+            let name = match prefix {
+                Some(prefix) => ident_from(&format!("{}_{}", prefix, index)),
+                None => format_ident!("_{}", index),
+            };
+            (name, Member::Unnamed(Index::from(index)))
+        }
+    }
+}
+
+/// Build an identifier, which is raw if the name is a keyword.
+fn ident_from(name: &str) -> Ident {
+    syn::parse_str(name).unwrap_or_else(|_| Ident::new_raw(name, Span::call_site()))
+}
+
+/// Check if a field is visible wherever the owner type is visible.
+fn is_visible(owner: &Visibility, field: &Visibility) -> bool {
+    match (owner, field) {
+        (Visibility::Inherited, _) => true,
+        (_, Visibility::Public(_)) => true,
+        (owner, field) => quote!(#owner).to_string() == quote!(#field).to_string(),
+    }
+}
+
+/// Remove the default value of a generic parameter, which is not allowed on traits.
+fn strip_default(param: &GenericParam) -> GenericParam {
+    let mut param = param.clone();
+    match &mut param {
+        GenericParam::Type(param) => {
+            param.eq_token = None;
+            param.default = None;
+        }
+        GenericParam::Const(param) => {
+            param.eq_token = None;
+            param.default = None;
+        }
+        GenericParam::Lifetime(_) => {}
+    }
+    param
+}
+
+/// Convert a camel case name to snake case.
+fn snake_case(name: &str) -> String {
+    let mut output = String::new();
+
+    for (index, ch) in name.char_indices() {
+        if ch.is_uppercase() {
+            if index != 0 {
+                output.push('_');
+            }
+            output.extend(ch.to_lowercase());
+        } else {
+            output.push(ch);
+        }
+    }
+
+    output
+}
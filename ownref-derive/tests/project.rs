@@ -0,0 +1,87 @@
+use ownref::{ArcOwnedC, ArcRefA, ArcRefC, BoxOwnedC, BoxRefC, Project};
+
+#[derive(Project)]
+pub struct Header {
+    pub name: String,
+    pub id: u32,
+}
+
+#[derive(Project)]
+pub struct Owner<'x, T> {
+    pub header: Header,
+    pub tag: &'x str,
+    pub data: T,
+    _private: u8,
+}
+
+#[derive(Project)]
+pub struct Pair(pub char, pub char);
+
+#[derive(Project)]
+pub enum Value<T> {
+    Text(String),
+    Point { x: i32, y: i32 },
+    Generic(T),
+    Empty,
+}
+
+fn owner() -> Owner<'static, Vec<u8>> {
+    Owner {
+        header: Header {
+            name: "name".into(),
+            id: 7,
+        },
+        tag: "tag",
+        data: vec![1, 2, 3],
+        _private: 0,
+    }
+}
+
+fn header_id<'a, P>(from: P) -> P::Output<u32>
+where
+    P: HeaderProject<'a>,
+{
+    from.id()
+}
+
+#[test]
+fn project_struct() {
+    let name: ArcRefC<Owner<Vec<u8>>, String> = ArcRefC::new(owner()).header().name();
+    assert_eq!(*name, "name");
+
+    let tag: ArcRefA<Owner<Vec<u8>>, &str> = ArcRefA::new(owner()).tag();
+    assert_eq!(*tag, "tag");
+
+    let data: ArcOwnedC<Owner<Vec<u8>>, &Vec<u8>> = ArcOwnedC::new(owner()).data();
+    assert_eq!(**data, [1, 2, 3]);
+
+    let id: ArcRefC<Owner<Vec<u8>>, u32> = header_id(ArcRefC::new(owner()).header());
+    assert_eq!(*id, 7);
+
+    let mut name: BoxRefC<Owner<Vec<u8>>, String> = BoxRefC::new(owner()).header().name();
+    name.push('!');
+    assert_eq!(BoxRefC::into_owner(name).header.name, "name!");
+
+    let mut second: BoxOwnedC<Pair, &mut char> = BoxOwnedC::new(Pair('a', 'b'))._1();
+    **second = 'c';
+    assert_eq!(BoxOwnedC::into_owner(second).1, 'c');
+}
+
+#[test]
+fn project_enum() {
+    let text = ArcRefC::new(Value::<u8>::Text("text".into()));
+    assert!(text.clone().point_x().is_none());
+    assert!(text.clone().generic().is_none());
+    assert_eq!(*text.text().unwrap(), "text");
+
+    let point = BoxRefC::new(Value::<u8>::Point { x: 1, y: 2 });
+    let mut y = point.point_y().unwrap();
+    *y = 3;
+    assert!(matches!(
+        BoxRefC::into_owner(y),
+        Value::Point { x: 1, y: 3 }
+    ));
+
+    let empty = ArcRefC::new(Value::<u8>::Empty);
+    assert!(empty.text().is_none());
+}
@@ -0,0 +1,101 @@
+use std::{
+    cmp, fmt,
+    fmt::Debug,
+    hash::{Hash, Hasher},
+    ptr,
+};
+
+/// Lookup key for pointer address ordered references.
+///
+/// It wraps the data and compares, orders and hashes by the address of the data, in the
+/// same way as the address ordered references such as `ArcRefA`. These references implement
+/// [Borrow](std::borrow::Borrow)`<Addr<I>>`, so that hash maps and B-tree maps keyed by them
+/// can be queried by any reference to the same data.
+///
+/// ```
+/// # use ownref::{Addr, ArcRefA};
+/// # use std::collections::HashMap;
+/// let owner = ArcRefA::new(['a', 'a']);
+/// let first: ArcRefA<[char; 2], char> = owner.clone().map(|array| &array[0]);
+/// let second: ArcRefA<[char; 2], char> = owner.map(|array| &array[1]);
+///
+/// let map = HashMap::from([(first.clone(), 0)]);
+/// assert_eq!(map.get(Addr::new(&*first)), Some(&0));
+/// assert_eq!(map.get(Addr::new(&*second)), None);
+/// ```
+#[repr(transparent)]
+pub struct Addr<T>(T)
+where
+    T: ?Sized;
+
+impl<T> Addr<T>
+where
+    T: ?Sized,
+{
+    /// Wrap the reference to `data` as a key compared by the address of `data`.
+    pub fn new(data: &T) -> &Addr<T> {
+        // Addr<T> is a transparent wrapper of T
+        unsafe { &*(data as *const T as *const Addr<T>) }
+    }
+
+    /// Get the reference to the data.
+    pub fn get(&self) -> &T {
+        &self.0
+    }
+
+    /// Get the pointer to the data.
+    pub fn as_ptr(&self) -> *const T {
+        &self.0
+    }
+}
+
+impl<T> Debug for Addr<T>
+where
+    T: ?Sized,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        f.debug_tuple("Addr").field(&self.as_ptr()).finish()
+    }
+}
+
+impl<T> PartialEq<Self> for Addr<T>
+where
+    T: ?Sized,
+{
+    fn eq(&self, other: &Self) -> bool {
+        ptr::eq(self.as_ptr(), other.as_ptr())
+    }
+}
+
+impl<T> Eq for Addr<T> where T: ?Sized {}
+
+impl<T> PartialOrd<Self> for Addr<T>
+where
+    T: ?Sized,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T> Ord for Addr<T>
+where
+    T: ?Sized,
+{
+    #[allow(ambiguous_wide_pointer_comparisons)]
+    fn cmp(&self, other: &Self) -> cmp::Ordering {
+        self.as_ptr().cmp(&other.as_ptr())
+    }
+}
+
+impl<T> Hash for Addr<T>
+where
+    T: ?Sized,
+{
+    fn hash<H>(&self, state: &mut H)
+    where
+        H: Hasher,
+    {
+        ptr::hash(self.as_ptr(), state);
+    }
+}
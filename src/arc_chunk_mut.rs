@@ -0,0 +1,255 @@
+use crate::{marker::*, utils::arc_is_unique};
+use std::{
+    cmp, fmt,
+    fmt::Debug,
+    hash::{Hash, Hasher},
+    marker::PhantomData,
+    ops::{Deref, DerefMut},
+    ptr::{self, NonNull},
+    sync::Arc,
+};
+
+/// Content ordered mutable reference to a disjoint chunk of data within an owner in [Arc].
+pub type ArcChunkMutC<'a, O, I> = ArcChunkMut<'a, O, I, ByContent>;
+
+/// Pointer address ordered mutable reference to a disjoint chunk of data within an owner in [Arc].
+pub type ArcChunkMutA<'a, O, I> = ArcChunkMut<'a, O, I, ByAddress>;
+
+/// Mutable reference to a disjoint chunk of data within an owner in [Arc].
+///
+/// It is created by `BoxRef::chunks_mut()`. The chunks share the same owner,
+/// and each of them can be mutated independently because they never overlap.
+/// The owner itself is not accessible until all chunks are dropped.
+pub struct ArcChunkMut<'a, O, I, E>
+where
+    O: ?Sized,
+    I: ?Sized,
+    E: EqKind,
+{
+    // inner goes before owner so that inner drops before owner
+    pub(crate) _phantom: PhantomData<(&'a mut I, E)>,
+    pub(crate) inner: NonNull<I>,
+    pub(crate) owner: Arc<O>,
+}
+
+unsafe impl<'a, O, I, E> Send for ArcChunkMut<'a, O, I, E>
+where
+    O: ?Sized + Send + Sync,
+    I: ?Sized + Send,
+    E: EqKind,
+{
+}
+
+unsafe impl<'a, O, I, E> Sync for ArcChunkMut<'a, O, I, E>
+where
+    O: ?Sized + Send + Sync,
+    I: ?Sized + Sync,
+    E: EqKind,
+{
+}
+
+impl<'a, O, I, E> ArcChunkMut<'a, O, I, E>
+where
+    O: ?Sized,
+    I: ?Sized,
+    E: EqKind,
+{
+    /// Return the owner in [Arc] if this is the only chunk left.
+    pub fn try_into_arc(mut from: ArcChunkMut<'a, O, I, E>) -> Result<Arc<O>, Self> {
+        if arc_is_unique(&mut from.owner) {
+            let Self { owner, .. } = from;
+            Ok(owner)
+        } else {
+            Err(from)
+        }
+    }
+
+    /// Get the strong count on the owner.
+    pub fn strong_count(this: &ArcChunkMut<'a, O, I, E>) -> usize {
+        Arc::strong_count(&this.owner)
+    }
+}
+
+impl<'a, O, I, E> ArcChunkMut<'a, O, I, E>
+where
+    I: ?Sized,
+    E: EqKind,
+{
+    /// Unwrap the owner if this is the only chunk left.
+    pub fn try_unwrap_owner(from: ArcChunkMut<'a, O, I, E>) -> Result<O, Self> {
+        let Self { owner, inner, .. } = from;
+
+        match Arc::try_unwrap(owner) {
+            Ok(owner) => Ok(owner),
+            Err(owner) => Err(Self {
+                owner,
+                inner,
+                _phantom: PhantomData,
+            }),
+        }
+    }
+}
+
+impl<'a, O, I, E> Debug for ArcChunkMut<'a, O, I, E>
+where
+    O: ?Sized,
+    I: ?Sized,
+    I: Debug,
+    E: EqKind,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        self.deref().fmt(f)
+    }
+}
+
+impl<'a, O, I> PartialEq<Self> for ArcChunkMut<'a, O, I, ByContent>
+where
+    O: ?Sized,
+    I: ?Sized,
+    I: PartialEq<I>,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.deref().eq(other.deref())
+    }
+}
+
+impl<'a, O, I> Eq for ArcChunkMut<'a, O, I, ByContent>
+where
+    I: Eq,
+    O: ?Sized,
+    I: ?Sized,
+{
+}
+
+impl<'a, O, I> PartialOrd<Self> for ArcChunkMut<'a, O, I, ByContent>
+where
+    O: ?Sized,
+    I: ?Sized,
+    I: PartialOrd<I>,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
+        self.deref().partial_cmp(other.deref())
+    }
+}
+
+impl<'a, O, I> Ord for ArcChunkMut<'a, O, I, ByContent>
+where
+    O: ?Sized,
+    I: ?Sized,
+    I: Ord,
+{
+    fn cmp(&self, other: &Self) -> cmp::Ordering {
+        self.deref().cmp(other.deref())
+    }
+}
+
+impl<'a, O, I> Hash for ArcChunkMut<'a, O, I, ByContent>
+where
+    O: ?Sized,
+    I: ?Sized + Hash,
+{
+    fn hash<H>(&self, state: &mut H)
+    where
+        H: Hasher,
+    {
+        self.deref().hash(state);
+    }
+}
+
+impl<'a, O, I> PartialEq<Self> for ArcChunkMut<'a, O, I, ByAddress>
+where
+    O: ?Sized,
+    I: ?Sized,
+{
+    fn eq(&self, other: &Self) -> bool {
+        ptr::eq(self.inner.as_ptr(), other.inner.as_ptr())
+    }
+}
+
+impl<'a, O, I> Eq for ArcChunkMut<'a, O, I, ByAddress>
+where
+    O: ?Sized,
+    I: ?Sized,
+{
+}
+
+impl<'a, O, I> PartialOrd<Self> for ArcChunkMut<'a, O, I, ByAddress>
+where
+    O: ?Sized,
+    I: ?Sized,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<'a, O, I> Ord for ArcChunkMut<'a, O, I, ByAddress>
+where
+    O: ?Sized,
+    I: ?Sized,
+{
+    #[allow(ambiguous_wide_pointer_comparisons)]
+    fn cmp(&self, other: &Self) -> cmp::Ordering {
+        (self.inner.as_ptr() as *const I).cmp(&(other.inner.as_ptr() as *const I))
+    }
+}
+
+impl<'a, O, I> Hash for ArcChunkMut<'a, O, I, ByAddress>
+where
+    O: ?Sized,
+    I: ?Sized,
+{
+    fn hash<H>(&self, state: &mut H)
+    where
+        H: Hasher,
+    {
+        ptr::hash(self.inner.as_ptr() as *const I, state);
+    }
+}
+
+impl<'a, O, I, E> AsRef<I> for ArcChunkMut<'a, O, I, E>
+where
+    O: ?Sized,
+    I: ?Sized,
+    E: EqKind,
+{
+    fn as_ref(&self) -> &I {
+        self.deref()
+    }
+}
+
+impl<'a, O, I, E> AsMut<I> for ArcChunkMut<'a, O, I, E>
+where
+    O: ?Sized,
+    I: ?Sized,
+    E: EqKind,
+{
+    fn as_mut(&mut self) -> &mut I {
+        self.deref_mut()
+    }
+}
+
+impl<'a, O, I, E> Deref for ArcChunkMut<'a, O, I, E>
+where
+    O: ?Sized,
+    I: ?Sized,
+    E: EqKind,
+{
+    type Target = I;
+
+    fn deref(&self) -> &Self::Target {
+        unsafe { self.inner.as_ref() }
+    }
+}
+
+impl<'a, O, I, E> DerefMut for ArcChunkMut<'a, O, I, E>
+where
+    O: ?Sized,
+    I: ?Sized,
+    E: EqKind,
+{
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        // chunks never overlap, so this is the only access to the data
+        unsafe { self.inner.as_mut() }
+    }
+}
@@ -0,0 +1,187 @@
+use crate::{arc_ref::ArcRef, marker::*};
+use std::{collections::VecDeque, fmt, fmt::Debug, marker::PhantomData, ptr};
+
+/// Content ordered cursor on a tree of nodes within an owner in [Arc](std::sync::Arc).
+pub type ArcCursorC<'a, O, N> = ArcCursor<'a, O, N, ByContent>;
+
+/// Pointer address ordered cursor on a tree of nodes within an owner in [Arc](std::sync::Arc).
+pub type ArcCursorA<'a, O, N> = ArcCursor<'a, O, N, ByAddress>;
+
+/// Node types in a tree that can be navigated by [ArcCursor].
+pub trait Children {
+    /// Iterate over the child nodes.
+    fn children(&self) -> impl Iterator<Item = &Self>;
+}
+
+/// Cursor on a tree of nodes within an owner in [Arc](std::sync::Arc).
+///
+/// It wraps an [ArcRef] to the current node and remembers the path of ancestors
+/// from the root, so that it can go back to the parent and siblings.
+pub struct ArcCursor<'a, O, N, E>
+where
+    O: ?Sized,
+    N: ?Sized,
+    E: EqKind,
+{
+    pub(crate) ancestors: Vec<&'a N>,
+    pub(crate) node: ArcRef<'a, O, N, E>,
+}
+
+impl<'a, O, N, E> ArcCursor<'a, O, N, E>
+where
+    O: ?Sized,
+    N: ?Sized + Children,
+    E: EqKind,
+{
+    /// Build a cursor with `root` as the root node.
+    pub fn new(root: ArcRef<'a, O, N, E>) -> Self {
+        Self {
+            ancestors: vec![],
+            node: root,
+        }
+    }
+
+    /// Get the reference to the current node.
+    pub fn node(&self) -> &ArcRef<'a, O, N, E> {
+        &self.node
+    }
+
+    /// Discard the path and return the reference to the current node.
+    pub fn into_node(self) -> ArcRef<'a, O, N, E> {
+        self.node
+    }
+
+    /// Get the number of ancestors of the current node.
+    pub fn depth(&self) -> usize {
+        self.ancestors.len()
+    }
+
+    /// Move to the root node.
+    pub fn root(&self) -> Self {
+        match self.ancestors.first() {
+            Some(&root) => self.with_path(vec![], root),
+            None => self.clone(),
+        }
+    }
+
+    /// Move to the parent node, or return `None` on the root node.
+    pub fn parent(&self) -> Option<Self> {
+        let (&parent, ancestors) = self.ancestors.split_last()?;
+        Some(self.with_path(ancestors.to_vec(), parent))
+    }
+
+    /// Iterate over cursors on the child nodes.
+    pub fn children(&self) -> impl Iterator<Item = Self> {
+        let this = self.clone();
+        let node: &'a N = self.node.inner;
+
+        node.children().map(move |child| {
+            let mut ancestors = this.ancestors.clone();
+            ancestors.push(node);
+            this.with_path(ancestors, child)
+        })
+    }
+
+    /// Move to the next sibling, or return `None` on the last child or the root node.
+    pub fn next_sibling(&self) -> Option<Self> {
+        let parent = *self.ancestors.last()?;
+        let sibling = parent
+            .children()
+            .skip_while(|&child| !ptr::eq(child, self.node.inner))
+            .nth(1)?;
+        Some(self.with_path(self.ancestors.clone(), sibling))
+    }
+
+    /// Move to the previous sibling, or return `None` on the first child or the root node.
+    pub fn prev_sibling(&self) -> Option<Self> {
+        let parent = *self.ancestors.last()?;
+        let sibling = parent
+            .children()
+            .take_while(|&child| !ptr::eq(child, self.node.inner))
+            .last()?;
+        Some(self.with_path(self.ancestors.clone(), sibling))
+    }
+
+    /// Traverse the subtree of the current node in depth-first pre-order.
+    pub fn depth_first(&self) -> impl Iterator<Item = ArcRef<'a, O, N, E>> {
+        let this = self.clone();
+        let mut stack: Vec<&'a N> = vec![self.node.inner];
+
+        std::iter::from_fn(move || {
+            let node = stack.pop()?;
+            let len = stack.len();
+            stack.extend(node.children());
+            stack[len..].reverse();
+            Some(this.with_node(node))
+        })
+    }
+
+    /// Traverse the subtree of the current node in breadth-first order.
+    pub fn breadth_first(&self) -> impl Iterator<Item = ArcRef<'a, O, N, E>> {
+        let this = self.clone();
+        let mut queue: VecDeque<&'a N> = VecDeque::from([self.node.inner]);
+
+        std::iter::from_fn(move || {
+            let node = queue.pop_front()?;
+            queue.extend(node.children());
+            Some(this.with_node(node))
+        })
+    }
+
+    /// Build a reference to `node` sharing the same owner.
+    fn with_node(&self, node: &'a N) -> ArcRef<'a, O, N, E> {
+        ArcRef {
+            owner: self.node.owner.clone(),
+            inner: node,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Build a cursor on `node` with the path `ancestors`.
+    fn with_path(&self, ancestors: Vec<&'a N>, node: &'a N) -> Self {
+        Self {
+            ancestors,
+            node: self.with_node(node),
+        }
+    }
+}
+
+impl<'a, O, N, E> Clone for ArcCursor<'a, O, N, E>
+where
+    O: ?Sized,
+    N: ?Sized,
+    E: EqKind,
+{
+    fn clone(&self) -> Self {
+        Self {
+            ancestors: self.ancestors.clone(),
+            node: self.node.clone(),
+        }
+    }
+}
+
+impl<'a, O, N, E> Debug for ArcCursor<'a, O, N, E>
+where
+    O: ?Sized,
+    N: ?Sized,
+    N: Debug,
+    E: EqKind,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        f.debug_struct("ArcCursor")
+            .field("depth", &self.ancestors.len())
+            .field("node", &self.node)
+            .finish()
+    }
+}
+
+impl<'a, O, N, E> From<ArcRef<'a, O, N, E>> for ArcCursor<'a, O, N, E>
+where
+    O: ?Sized,
+    N: ?Sized + Children,
+    E: EqKind,
+{
+    fn from(root: ArcRef<'a, O, N, E>) -> Self {
+        Self::new(root)
+    }
+}
@@ -0,0 +1,297 @@
+use crate::{addr::Addr, arc_ref::ArcRef, field::Field, marker::*};
+use std::{
+    borrow::Borrow,
+    cmp, fmt,
+    fmt::{Debug, Display},
+    hash::{Hash, Hasher},
+    marker::PhantomData,
+    ops::Deref,
+    ptr,
+    sync::Arc,
+};
+
+/// Content ordered field within an owner in [Arc].
+pub type ArcFieldC<O, I = O> = ArcField<O, I, ByContent>;
+
+/// Pointer address ordered field within an owner in [Arc].
+pub type ArcFieldA<O, I = O> = ArcField<O, I, ByAddress>;
+
+/// Field within an owner in [Arc], which is located by a byte offset.
+///
+/// It stores the owner and a [Field] instead of a reference, so that it
+/// is two words in size and can be re-applied to another owner.
+pub struct ArcField<O, I, E>
+where
+    E: EqKind,
+{
+    pub(crate) _phantom: PhantomData<E>,
+    pub(crate) field: Field<O, I>,
+    pub(crate) owner: Arc<O>,
+}
+
+unsafe impl<O, I, E> Send for ArcField<O, I, E>
+where
+    O: Send + Sync,
+    I: Sync,
+    E: EqKind,
+{
+}
+
+unsafe impl<O, I, E> Sync for ArcField<O, I, E>
+where
+    O: Send + Sync,
+    I: Sync,
+    E: EqKind,
+{
+}
+
+impl<O, E> ArcField<O, O, E>
+where
+    E: EqKind,
+{
+    /// Build from an owner.
+    pub fn new(owner: O) -> Self {
+        Arc::new(owner).into()
+    }
+
+    /// Build from owner data in [Arc].
+    pub fn from_arc(owner: Arc<O>) -> Self {
+        owner.into()
+    }
+}
+
+impl<O, I, E> ArcField<O, I, E>
+where
+    E: EqKind,
+{
+    /// Build from owner data in [Arc] and a field within the owner.
+    pub fn from_field(owner: Arc<O>, field: Field<O, I>) -> Self {
+        Self {
+            owner,
+            field,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Discard the field and return the owner in [Arc].
+    pub fn into_arc(from: ArcField<O, I, E>) -> Arc<O> {
+        let Self { owner, .. } = from;
+        owner
+    }
+
+    /// Convert to [ArcRef].
+    pub fn into_arc_ref<'a>(this: ArcField<O, I, E>) -> ArcRef<'a, O, I, E>
+    where
+        I: 'a,
+    {
+        let Self { owner, field, .. } = this;
+
+        unsafe {
+            // re-borrow to obtain 'a lifetime
+            let inner = &*field.project(Arc::as_ptr(&owner));
+
+            ArcRef {
+                inner,
+                owner,
+                _phantom: PhantomData,
+            }
+        }
+    }
+
+    /// Get the field descriptor.
+    pub fn field(this: &ArcField<O, I, E>) -> Field<O, I> {
+        this.field
+    }
+
+    /// Get the reference to the owner.
+    pub fn owner(this: &ArcField<O, I, E>) -> &O {
+        &this.owner
+    }
+
+    /// Get the strong count on the owner.
+    pub fn strong_count(this: &ArcField<O, I, E>) -> usize {
+        Arc::strong_count(&this.owner)
+    }
+
+    /// Get the weak count on the owner.
+    pub fn weak_count(this: &ArcField<O, I, E>) -> usize {
+        Arc::weak_count(&this.owner)
+    }
+
+    /// Apply the same field to another `owner`.
+    pub fn with_owner(this: &ArcField<O, I, E>, owner: Arc<O>) -> ArcField<O, I, E> {
+        Self::from_field(owner, this.field)
+    }
+
+    /// Project to the sub-field `field` of the current field.
+    pub fn map<T>(self, field: Field<I, T>) -> ArcField<O, T, E> {
+        let Self {
+            owner, field: orig, ..
+        } = self;
+        ArcField::from_field(owner, orig.then(field))
+    }
+
+    /// Unwrap the owner if strong count is one.
+    pub fn try_unwrap_owner(from: ArcField<O, I, E>) -> Result<O, Self> {
+        let Self { owner, field, .. } = from;
+
+        match Arc::try_unwrap(owner) {
+            Ok(owner) => Ok(owner),
+            Err(owner) => Err(Self::from_field(owner, field)),
+        }
+    }
+
+    /// Unwrap the owner and panic if strong count is one.
+    ///
+    /// # Panic
+    /// The method panics if strong count is not 1.
+    pub fn unwrap_owner(from: ArcField<O, I, E>) -> O {
+        Self::try_unwrap_owner(from)
+            .unwrap_or_else(|_| panic!("unable to unwrap because strong count is greater than 1"))
+    }
+
+    fn as_ptr(&self) -> *const I {
+        self.field.project(Arc::as_ptr(&self.owner))
+    }
+}
+
+impl<O, I, E> Clone for ArcField<O, I, E>
+where
+    E: EqKind,
+{
+    /// Copy the field and increase reference count to owner.
+    fn clone(&self) -> Self {
+        Self::from_field(self.owner.clone(), self.field)
+    }
+}
+
+impl<O, I, E> Debug for ArcField<O, I, E>
+where
+    I: Debug,
+    E: EqKind,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        self.deref().fmt(f)
+    }
+}
+
+impl<O, I, E> Display for ArcField<O, I, E>
+where
+    I: Display,
+    E: EqKind,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        self.deref().fmt(f)
+    }
+}
+
+impl<O, I> PartialEq<Self> for ArcField<O, I, ByContent>
+where
+    I: PartialEq<I>,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.deref().eq(other.deref())
+    }
+}
+
+impl<O, I> Eq for ArcField<O, I, ByContent> where I: Eq {}
+
+impl<O, I> PartialOrd<Self> for ArcField<O, I, ByContent>
+where
+    I: PartialOrd<I>,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
+        self.deref().partial_cmp(other.deref())
+    }
+}
+
+impl<O, I> Ord for ArcField<O, I, ByContent>
+where
+    I: Ord,
+{
+    fn cmp(&self, other: &Self) -> cmp::Ordering {
+        self.deref().cmp(other.deref())
+    }
+}
+
+impl<O, I> Hash for ArcField<O, I, ByContent>
+where
+    I: Hash,
+{
+    fn hash<H>(&self, state: &mut H)
+    where
+        H: Hasher,
+    {
+        self.deref().hash(state);
+    }
+}
+
+impl<O, I> PartialEq<Self> for ArcField<O, I, ByAddress> {
+    fn eq(&self, other: &Self) -> bool {
+        ptr::eq(self.as_ptr(), other.as_ptr())
+    }
+}
+
+impl<O, I> Eq for ArcField<O, I, ByAddress> {}
+
+impl<O, I> PartialOrd<Self> for ArcField<O, I, ByAddress> {
+    fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<O, I> Ord for ArcField<O, I, ByAddress> {
+    fn cmp(&self, other: &Self) -> cmp::Ordering {
+        self.as_ptr().cmp(&other.as_ptr())
+    }
+}
+
+impl<O, I> Hash for ArcField<O, I, ByAddress> {
+    fn hash<H>(&self, state: &mut H)
+    where
+        H: Hasher,
+    {
+        ptr::hash(self.as_ptr(), state);
+    }
+}
+
+impl<O, I, E> AsRef<I> for ArcField<O, I, E>
+where
+    E: EqKind,
+{
+    fn as_ref(&self) -> &I {
+        self.deref()
+    }
+}
+
+impl<O, I> Borrow<I> for ArcField<O, I, ByContent> {
+    fn borrow(&self) -> &I {
+        self.deref()
+    }
+}
+
+impl<O, I> Borrow<Addr<I>> for ArcField<O, I, ByAddress> {
+    fn borrow(&self) -> &Addr<I> {
+        Addr::new(self.deref())
+    }
+}
+
+impl<O, I, E> Deref for ArcField<O, I, E>
+where
+    E: EqKind,
+{
+    type Target = I;
+
+    fn deref(&self) -> &Self::Target {
+        unsafe { &*self.as_ptr() }
+    }
+}
+
+impl<O, E> From<Arc<O>> for ArcField<O, O, E>
+where
+    E: EqKind,
+{
+    fn from(owner: Arc<O>) -> Self {
+        Self::from_field(owner, Field::identity())
+    }
+}
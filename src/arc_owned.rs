@@ -1,6 +1,9 @@
-use crate::{arc_ref::ArcRef, marker::*};
+use crate::{
+    addr::Addr, arc_owned_view::ArcOwnedView, arc_ref::ArcRef, local_arc_ref::LocalArcRef,
+    marker::*, utils::arc_is_unique,
+};
 use std::{
-    any::Any,
+    any::{Any, TypeId},
     borrow::Borrow,
     cmp, fmt,
     fmt::{Debug, Display},
@@ -11,6 +14,8 @@ use std::{
     ptr,
     sync::Arc,
 };
+#[cfg(feature = "unsize")]
+use std::{marker::Unsize, ops::CoerceUnsized};
 
 /// Content ordered owned data bundled with an owner in [Arc].
 pub type ArcOwnedC<'a, O, I = &'a O> = ArcOwned<'a, O, I, ByContent>;
@@ -24,6 +29,14 @@ pub type ArcOwnedAnyC<'a, I> = ArcOwned<'a, dyn Any + Send + Sync + 'static, I,
 /// Pointer address ordered owned data bundled with an [Any] owner in [Arc].
 pub type ArcOwnedAnyA<'a, I> = ArcOwned<'a, dyn Any + Send + Sync + 'static, I, ByAddress>;
 
+/// Content ordered owned data bundled with an [Any] owner in [Arc], without the [Send] + [Sync]
+/// requirement on the owner.
+pub type ArcOwnedAnyLocalC<'a, I> = ArcOwned<'a, dyn Any + 'static, I, ByContent>;
+
+/// Pointer address ordered owned data bundled with an [Any] owner in [Arc], without the [Send] + [Sync]
+/// requirement on the owner.
+pub type ArcOwnedAnyLocalA<'a, I> = ArcOwned<'a, dyn Any + 'static, I, ByAddress>;
+
 /// Owned data bundled with an owner in [Arc].
 pub struct ArcOwned<'a, O, I, E>
 where
@@ -59,7 +72,7 @@ where
     }
 
     /// Reset data to reference to owner.
-    pub fn into_owner_ref(this: ArcOwned<'a, O, I, E>) -> ArcOwned<'a, O, &O, E> {
+    pub fn into_owner_ref(this: ArcOwned<'a, O, I, E>) -> ArcOwned<'a, O, &'a O, E> {
         let Self { owner, inner, .. } = this;
         drop(inner);
 
@@ -80,6 +93,51 @@ where
         &this.owner
     }
 
+    /// Change the owner type by the coercion `f`, for example, to a trait object.
+    ///
+    /// Prefer the safe [coerce_owner!](crate::coerce_owner!) macro, or `coerce_owner_unsize()`
+    /// with the `unsize` feature. The function `f` is usually the cast `|owner| owner as _`
+    /// with the target type annotated, which performs the unsizing coercion to
+    /// `Arc<dyn Trait>`.
+    ///
+    /// # Panic
+    /// The method panics if `f` returns an owner at a different address.
+    ///
+    /// # Safety
+    /// The function `f` must return the same owner with only its type changed. Replacing
+    /// the owner leaves the inner reference dangling, even if the new owner happens to be
+    /// allocated at the same address.
+    pub unsafe fn coerce_owner<U, F>(from: ArcOwned<'a, O, I, E>, f: F) -> ArcOwned<'a, U, I, E>
+    where
+        U: ?Sized,
+        F: FnOnce(Arc<O>) -> Arc<U>,
+    {
+        let Self { owner, inner, .. } = from;
+        let addr = &*owner as *const O as *const u8;
+        let owner = f(owner);
+        assert!(
+            ptr::eq(addr, &*owner as *const U as *const u8),
+            "the coercion must not replace the owner"
+        );
+
+        ArcOwned {
+            inner,
+            owner,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Change the owner type by the unsizing coercion, for example, to a trait object.
+    #[cfg(feature = "unsize")]
+    pub fn coerce_owner_unsize<U>(from: ArcOwned<'a, O, I, E>) -> ArcOwned<'a, U, I, E>
+    where
+        O: Unsize<U>,
+        U: ?Sized,
+    {
+        // the coercion keeps the same owner
+        unsafe { Self::coerce_owner(from, |owner| owner as _) }
+    }
+
     /// Get strong count on owner.
     pub fn strong_count(this: &ArcOwned<'a, O, I, E>) -> usize {
         Arc::strong_count(&this.owner)
@@ -90,6 +148,15 @@ where
         Arc::weak_count(&this.owner)
     }
 
+    /// Get mutable reference to data if strong count is one and weak count is zero.
+    pub fn get_mut(this: &mut Self) -> Option<&mut I> {
+        if arc_is_unique(&mut this.owner) {
+            Some(&mut this.inner)
+        } else {
+            None
+        }
+    }
+
     /// Applies function `f` to data.
     pub fn map<T, F>(self, f: F) -> ArcOwned<'a, O, T, E>
     where
@@ -181,6 +248,27 @@ where
         })
     }
 
+    /// Iterate over views to the items of a copy of the iterable data.
+    ///
+    /// The views borrow the owner from this reference without increasing the reference count.
+    pub fn iter_views<'v>(
+        this: &'v ArcOwned<'a, O, I, E>,
+    ) -> impl Iterator<Item = ArcOwnedView<'v, 'a, O, I::Item, E>>
+    where
+        I: Clone + IntoIterator,
+    {
+        let owner = &this.owner;
+
+        this.inner
+            .clone()
+            .into_iter()
+            .map(move |item| ArcOwnedView {
+                owner,
+                inner: item,
+                _phantom: PhantomData,
+            })
+    }
+
     /// Apply fucntion `f` to get an iterable type, and flatten it to an iterator of wrapped items.
     pub fn flat_map<T, F>(self, f: F) -> impl Iterator<Item = ArcOwned<'a, O, T::Item, E>>
     where
@@ -219,6 +307,22 @@ where
         }
     }
 
+    /// Change the owner type to [Any] trait object, which is not required to be [Send] or [Sync].
+    pub fn into_any_owner_local(
+        from: ArcOwned<'a, O, I, E>,
+    ) -> ArcOwned<'a, dyn Any + 'static, I, E>
+    where
+        O: 'static,
+    {
+        let Self { owner, inner, .. } = from;
+
+        ArcOwned {
+            inner,
+            owner,
+            _phantom: PhantomData,
+        }
+    }
+
     /// Unwrap the owner if strong count is one.
     pub fn try_unwrap_owner(from: ArcOwned<'a, O, I, E>) -> Result<O, Self> {
         let Self { owner, inner, .. } = from;
@@ -259,6 +363,11 @@ where
             _phantom: PhantomData,
         }
     }
+
+    /// Convert to [LocalArcRef], which is cheaply cloned and flattened within a thread.
+    pub fn into_local(this: ArcOwned<'a, O, &'a I, E>) -> LocalArcRef<'a, O, I, E> {
+        LocalArcRef::from_arc_ref(Self::into_arc_ref(this))
+    }
 }
 
 impl<'a, O, I, E> ArcOwned<'a, O, Option<I>, E>
@@ -297,6 +406,19 @@ impl<'a, I, E> ArcOwned<'a, dyn Any + Send + Sync + 'static, I, E>
 where
     E: EqKind,
 {
+    /// Get the [TypeId] of the concrete owner type.
+    pub fn owner_type_id(this: &Self) -> TypeId {
+        (*this.owner).type_id()
+    }
+
+    /// Get the reference to the owner if the owner is of type `O`.
+    pub fn owner_downcast_ref<O>(this: &Self) -> Option<&O>
+    where
+        O: 'static,
+    {
+        this.owner.downcast_ref()
+    }
+
     /// Downcast the [Any]-trait object owner to concrete type.
     pub fn downcast_owner<O>(this: Self) -> Result<ArcOwned<'a, O, I, E>, Self>
     where
@@ -319,6 +441,76 @@ where
     }
 }
 
+impl<'a, I, E> ArcOwned<'a, dyn Any + 'static, I, E>
+where
+    E: EqKind,
+{
+    /// Get the [TypeId] of the concrete owner type.
+    pub fn owner_type_id(this: &Self) -> TypeId {
+        (*this.owner).type_id()
+    }
+
+    /// Get the reference to the owner if the owner is of type `O`.
+    pub fn owner_downcast_ref<O>(this: &Self) -> Option<&O>
+    where
+        O: 'static,
+    {
+        this.owner.downcast_ref()
+    }
+
+    /// Downcast the [Any] trait object owner to concrete type.
+    pub fn downcast_owner_local<O>(this: Self) -> Result<ArcOwned<'a, O, I, E>, Self>
+    where
+        O: 'static,
+    {
+        let Self { owner, inner, .. } = this;
+
+        if owner.is::<O>() {
+            // the pointer is cast to the concrete type which is checked above
+            let owner = unsafe { Arc::from_raw(Arc::into_raw(owner) as *const O) };
+
+            Ok(ArcOwned {
+                owner,
+                inner,
+                _phantom: PhantomData,
+            })
+        } else {
+            Err(ArcOwned {
+                owner,
+                inner,
+                _phantom: PhantomData,
+            })
+        }
+    }
+}
+
+impl<'a, O, E> ArcOwned<'a, O, &'a (dyn Any + 'static), E>
+where
+    O: ?Sized,
+    E: EqKind,
+{
+    /// Downcast the [Any] trait object data to concrete type.
+    pub fn downcast_inner<T>(this: Self) -> Result<ArcOwned<'a, O, &'a T, E>, Self>
+    where
+        T: 'static,
+    {
+        let Self { owner, inner, .. } = this;
+
+        match inner.downcast_ref() {
+            Some(inner) => Ok(ArcOwned {
+                owner,
+                inner,
+                _phantom: PhantomData,
+            }),
+            None => Err(ArcOwned {
+                owner,
+                inner,
+                _phantom: PhantomData,
+            }),
+        }
+    }
+}
+
 impl<'a, O, I, E> Clone for ArcOwned<'a, O, I, E>
 where
     O: ?Sized,
@@ -432,7 +624,7 @@ where
     I: ?Sized,
 {
     fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
-        (self.inner as *const I).partial_cmp(&(other.inner as *const I))
+        Some(self.cmp(other))
     }
 }
 
@@ -441,6 +633,7 @@ where
     O: ?Sized,
     I: ?Sized,
 {
+    #[allow(ambiguous_wide_pointer_comparisons)]
     fn cmp(&self, other: &Self) -> cmp::Ordering {
         (self.inner as *const I).cmp(&(other.inner as *const I))
     }
@@ -468,16 +661,25 @@ where
     }
 }
 
-impl<'a, O, I, E> Borrow<I> for ArcOwned<'a, O, I, E>
+impl<'a, O, I> Borrow<I> for ArcOwned<'a, O, I, ByContent>
 where
     O: ?Sized,
-    E: EqKind,
 {
     fn borrow(&self) -> &I {
         self.deref()
     }
 }
 
+impl<'a, O, I> Borrow<Addr<I>> for ArcOwned<'a, O, &'a I, ByAddress>
+where
+    O: ?Sized,
+    I: ?Sized,
+{
+    fn borrow(&self) -> &Addr<I> {
+        Addr::new(self.inner)
+    }
+}
+
 impl<'a, O, I, E> Deref for ArcOwned<'a, O, I, E>
 where
     O: ?Sized,
@@ -508,3 +710,12 @@ where
         }
     }
 }
+
+#[cfg(feature = "unsize")]
+impl<'a, O, I, U, E> CoerceUnsized<ArcOwned<'a, O, U, E>> for ArcOwned<'a, O, I, E>
+where
+    O: ?Sized,
+    I: CoerceUnsized<U>,
+    E: EqKind,
+{
+}
@@ -0,0 +1,248 @@
+use crate::{addr::Addr, arc_owned::ArcOwned, marker::*};
+use std::{
+    borrow::Borrow,
+    cmp, fmt,
+    fmt::{Debug, Display},
+    hash::{Hash, Hasher},
+    marker::PhantomData,
+    ops::Deref,
+    ptr,
+    sync::Arc,
+};
+
+/// Content ordered view to owned data bundled with an owner borrowed from an [ArcOwned].
+pub type ArcOwnedViewC<'v, 'a, O, I = &'a O> = ArcOwnedView<'v, 'a, O, I, ByContent>;
+
+/// Pointer address ordered view to owned data bundled with an owner borrowed from an [ArcOwned].
+pub type ArcOwnedViewA<'v, 'a, O, I = &'a O> = ArcOwnedView<'v, 'a, O, I, ByAddress>;
+
+/// Owned data bundled with an owner, which borrows the owner from an [ArcOwned].
+///
+/// It is created by [ArcOwned::iter_views()] without touching the reference count,
+/// and it is ordered in the same way as [ArcOwned]. The views to be kept are promoted
+/// to [ArcOwned] by [ArcOwnedView::into_arc_owned()].
+pub struct ArcOwnedView<'v, 'a, O, I, E>
+where
+    O: ?Sized,
+    E: EqKind,
+{
+    pub(crate) _phantom: PhantomData<(&'a I, E)>,
+    pub(crate) inner: I,
+    pub(crate) owner: &'v Arc<O>,
+}
+
+impl<'v, 'a, O, I, E> ArcOwnedView<'v, 'a, O, I, E>
+where
+    O: ?Sized,
+    E: EqKind,
+{
+    /// Promote to [ArcOwned] by increasing the reference count to owner.
+    pub fn into_arc_owned(this: ArcOwnedView<'v, 'a, O, I, E>) -> ArcOwned<'a, O, I, E> {
+        let Self { owner, inner, .. } = this;
+
+        ArcOwned {
+            owner: owner.clone(),
+            inner,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Get the reference to the owner.
+    pub fn owner(this: &ArcOwnedView<'v, 'a, O, I, E>) -> &'v O {
+        this.owner
+    }
+
+    /// Apply function `f` to the owned data.
+    pub fn map<T, F>(self, f: F) -> ArcOwnedView<'v, 'a, O, T, E>
+    where
+        F: FnOnce(I) -> T,
+    {
+        let Self { owner, inner, .. } = self;
+
+        ArcOwnedView {
+            owner,
+            inner: f(inner),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<'v, 'a, O, I, E> Clone for ArcOwnedView<'v, 'a, O, I, E>
+where
+    O: ?Sized,
+    I: Clone,
+    E: EqKind,
+{
+    fn clone(&self) -> Self {
+        let Self { owner, inner, .. } = self;
+
+        Self {
+            owner,
+            inner: inner.clone(),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<'v, 'a, O, I, E> Debug for ArcOwnedView<'v, 'a, O, I, E>
+where
+    O: ?Sized,
+    I: Debug,
+    E: EqKind,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        self.inner.fmt(f)
+    }
+}
+
+impl<'v, 'a, O, I, E> Display for ArcOwnedView<'v, 'a, O, I, E>
+where
+    O: ?Sized,
+    I: Display,
+    E: EqKind,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        self.inner.fmt(f)
+    }
+}
+
+impl<'v, 'a, O, I> PartialEq<Self> for ArcOwnedView<'v, 'a, O, I, ByContent>
+where
+    O: ?Sized,
+    I: PartialEq<I>,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.inner.eq(&other.inner)
+    }
+}
+
+impl<'v, 'a, O, I> Eq for ArcOwnedView<'v, 'a, O, I, ByContent>
+where
+    I: Eq,
+    O: ?Sized,
+{
+}
+
+impl<'v, 'a, O, I> PartialOrd<Self> for ArcOwnedView<'v, 'a, O, I, ByContent>
+where
+    O: ?Sized,
+    I: PartialOrd<I>,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
+        self.inner.partial_cmp(&other.inner)
+    }
+}
+
+impl<'v, 'a, O, I> Ord for ArcOwnedView<'v, 'a, O, I, ByContent>
+where
+    O: ?Sized,
+    I: Ord,
+{
+    fn cmp(&self, other: &Self) -> cmp::Ordering {
+        self.inner.cmp(&other.inner)
+    }
+}
+
+impl<'v, 'a, O, I> Hash for ArcOwnedView<'v, 'a, O, I, ByContent>
+where
+    O: ?Sized,
+    I: Hash,
+{
+    fn hash<H>(&self, state: &mut H)
+    where
+        H: Hasher,
+    {
+        self.inner.hash(state);
+    }
+}
+
+impl<'v, 'a, O, I> PartialEq<Self> for ArcOwnedView<'v, 'a, O, &'a I, ByAddress>
+where
+    O: ?Sized,
+    I: ?Sized,
+{
+    fn eq(&self, other: &Self) -> bool {
+        ptr::eq(self.inner as *const I, other.inner as *const I)
+    }
+}
+
+impl<'v, 'a, O, I> Eq for ArcOwnedView<'v, 'a, O, &'a I, ByAddress>
+where
+    O: ?Sized,
+    I: ?Sized,
+{
+}
+
+impl<'v, 'a, O, I> PartialOrd<Self> for ArcOwnedView<'v, 'a, O, &'a I, ByAddress>
+where
+    O: ?Sized,
+    I: ?Sized,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<'v, 'a, O, I> Ord for ArcOwnedView<'v, 'a, O, &'a I, ByAddress>
+where
+    O: ?Sized,
+    I: ?Sized,
+{
+    #[allow(ambiguous_wide_pointer_comparisons)]
+    fn cmp(&self, other: &Self) -> cmp::Ordering {
+        (self.inner as *const I).cmp(&(other.inner as *const I))
+    }
+}
+
+impl<'v, 'a, O, I> Hash for ArcOwnedView<'v, 'a, O, &'a I, ByAddress>
+where
+    O: ?Sized,
+{
+    fn hash<H>(&self, state: &mut H)
+    where
+        H: Hasher,
+    {
+        ptr::hash(self.inner as *const I, state);
+    }
+}
+
+impl<'v, 'a, O, I, E> AsRef<I> for ArcOwnedView<'v, 'a, O, I, E>
+where
+    O: ?Sized,
+    E: EqKind,
+{
+    fn as_ref(&self) -> &I {
+        self.deref()
+    }
+}
+
+impl<'v, 'a, O, I> Borrow<I> for ArcOwnedView<'v, 'a, O, I, ByContent>
+where
+    O: ?Sized,
+{
+    fn borrow(&self) -> &I {
+        self.deref()
+    }
+}
+
+impl<'v, 'a, O, I> Borrow<Addr<I>> for ArcOwnedView<'v, 'a, O, &'a I, ByAddress>
+where
+    O: ?Sized,
+    I: ?Sized,
+{
+    fn borrow(&self) -> &Addr<I> {
+        Addr::new(self.inner)
+    }
+}
+
+impl<'v, 'a, O, I, E> Deref for ArcOwnedView<'v, 'a, O, I, E>
+where
+    O: ?Sized,
+    E: EqKind,
+{
+    type Target = I;
+
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
@@ -1,6 +1,14 @@
-use crate::{arc_owned::ArcOwned, marker::*};
+use crate::{
+    addr::Addr,
+    arc_owned::ArcOwned,
+    arc_ref_view::ArcRefView,
+    local_arc_ref::LocalArcRef,
+    marker::*,
+    maybe_arc_ref::MaybeArcRef,
+    utils::{offset_within, Caller},
+};
 use std::{
-    any::Any,
+    any::{Any, TypeId},
     borrow::Borrow,
     cmp, fmt,
     fmt::{Debug, Display},
@@ -10,6 +18,8 @@ use std::{
     ptr,
     sync::Arc,
 };
+#[cfg(feature = "unsize")]
+use std::{marker::Unsize, ops::CoerceUnsized};
 
 /// Content ordered reference to data within an owner in [Arc].
 pub type ArcRefC<'a, O, I = O> = ArcRef<'a, O, I, ByContent>;
@@ -23,6 +33,14 @@ pub type ArcRefAnyC<'a, I> = ArcRef<'a, dyn Any + Send + Sync + 'static, I, ByCo
 /// Pointer address ordered reference to data within an [Any] owner in [Arc].
 pub type ArcRefAnyA<'a, I> = ArcRef<'a, dyn Any + Send + Sync + 'static, I, ByAddress>;
 
+/// Content ordered reference to data within an [Any] owner in [Arc], without the [Send] + [Sync]
+/// requirement on the owner.
+pub type ArcRefAnyLocalC<'a, I> = ArcRef<'a, dyn Any + 'static, I, ByContent>;
+
+/// Pointer address ordered reference to data within an [Any] owner in [Arc], without the [Send] + [Sync]
+/// requirement on the owner.
+pub type ArcRefAnyLocalA<'a, I> = ArcRef<'a, dyn Any + 'static, I, ByAddress>;
+
 /// Reference to data within an owner in [Arc].
 pub struct ArcRef<'a, O, I, E>
 where
@@ -53,6 +71,11 @@ where
     I: ?Sized,
     E: EqKind,
 {
+    /// Build a [MaybeArcRef] from static data, which needs neither an owner nor allocation.
+    pub const fn from_static(inner: &'static I) -> MaybeArcRef<'a, O, I, E> {
+        MaybeArcRef::from_static(inner)
+    }
+
     /// Discard the inner reference and return the owner in [Arc].
     pub fn into_arc(from: ArcRef<'a, O, I, E>) -> Arc<O> {
         let Self { owner, .. } = from;
@@ -69,6 +92,11 @@ where
         }
     }
 
+    /// Convert to [LocalArcRef], which is cheaply cloned and flattened within a thread.
+    pub fn into_local(this: ArcRef<'a, O, I, E>) -> LocalArcRef<'a, O, I, E> {
+        LocalArcRef::from_arc_ref(this)
+    }
+
     /// Reset the inner reference to the owner.
     pub fn into_owner_ref(this: ArcRef<'a, O, I, E>) -> ArcRef<'a, O, O, E> {
         let Self { owner, .. } = this;
@@ -85,11 +113,109 @@ where
         }
     }
 
+    /// Build from owner data in [Arc] and a pointer to the data within the owner.
+    ///
+    /// # Safety
+    /// The `inner` must be non-null and valid for reads as long as the `owner` is alive,
+    /// for example, a pointer to the data within the owner.
+    pub unsafe fn from_raw_parts(owner: Arc<O>, inner: *const I) -> Self {
+        Self {
+            inner: &*inner,
+            owner,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Consume and return the raw pointers to the owner and to the data.
+    ///
+    /// The reference count on the owner is retained. Use [ArcRef::from_raw()] to
+    /// convert the pointers back, or the owner is leaked.
+    pub fn into_raw(this: ArcRef<'a, O, I, E>) -> (*const O, *const I) {
+        let Self { owner, inner, .. } = this;
+        (Arc::into_raw(owner), inner)
+    }
+
+    /// Get the raw pointers to the owner and to the data without consuming.
+    pub fn as_raw(this: &ArcRef<'a, O, I, E>) -> (*const O, *const I) {
+        (Arc::as_ptr(&this.owner), this.inner)
+    }
+
+    /// Build from the raw pointers returned by [ArcRef::into_raw()].
+    ///
+    /// # Safety
+    /// The pointers must be returned by [ArcRef::into_raw()] on an `ArcRef` of the same
+    /// owner and data types, and each pair of pointers is converted back at most once.
+    pub unsafe fn from_raw(owner: *const O, inner: *const I) -> Self {
+        Self::from_raw_parts(Arc::from_raw(owner), inner)
+    }
+
+    /// Increase the strong count on the owner of the raw pointer returned by [ArcRef::into_raw()].
+    ///
+    /// # Safety
+    /// The `owner` must be returned by [ArcRef::into_raw()], and the owner must be alive.
+    pub unsafe fn increment_strong_count(owner: *const O) {
+        Arc::increment_strong_count(owner)
+    }
+
+    /// Decrease the strong count on the owner of the raw pointer returned by [ArcRef::into_raw()].
+    ///
+    /// # Safety
+    /// The `owner` must be returned by [ArcRef::into_raw()], and the strong count must be
+    /// at least one. The owner is dropped if the count reaches zero.
+    pub unsafe fn decrement_strong_count(owner: *const O) {
+        Arc::decrement_strong_count(owner)
+    }
+
     /// Get the reference to the owner.
     pub fn owner(this: &'a ArcRef<'a, O, I, E>) -> &'a O {
         &this.owner
     }
 
+    /// Change the owner type by the coercion `f`, for example, to a trait object.
+    ///
+    /// Prefer the safe [coerce_owner!](crate::coerce_owner!) macro, or `coerce_owner_unsize()`
+    /// with the `unsize` feature. The function `f` is usually the cast `|owner| owner as _`
+    /// with the target type annotated, which performs the unsizing coercion to
+    /// `Arc<dyn Trait>`.
+    ///
+    /// # Panic
+    /// The method panics if `f` returns an owner at a different address.
+    ///
+    /// # Safety
+    /// The function `f` must return the same owner with only its type changed. Replacing
+    /// the owner leaves the inner reference dangling, even if the new owner happens to be
+    /// allocated at the same address.
+    pub unsafe fn coerce_owner<U, F>(from: ArcRef<'a, O, I, E>, f: F) -> ArcRef<'a, U, I, E>
+    where
+        U: ?Sized,
+        F: FnOnce(Arc<O>) -> Arc<U>,
+    {
+        let Self { owner, inner, .. } = from;
+        let addr = &*owner as *const O as *const u8;
+        let owner = f(owner);
+        assert!(
+            ptr::eq(addr, &*owner as *const U as *const u8),
+            "the coercion must not replace the owner"
+        );
+
+        ArcRef {
+            inner,
+            owner,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Change the owner type by the unsizing coercion, for example, to a trait object.
+    #[cfg(feature = "unsize")]
+    pub fn coerce_owner_unsize<U>(from: ArcRef<'a, O, I, E>) -> ArcRef<'a, U, I, E>
+    where
+        O: Unsize<U>,
+        U: ?Sized,
+    {
+        // the coercion keeps the same owner
+        unsafe { Self::coerce_owner(from, |owner| owner as _) }
+    }
+
     /// Get the strong count on the owner.
     pub fn strong_count(this: &ArcRef<'a, O, I, E>) -> usize {
         Arc::strong_count(&this.owner)
@@ -100,7 +226,15 @@ where
         Arc::weak_count(&this.owner)
     }
 
+    /// Check if the referenced data lies within the memory of the owner.
+    ///
+    /// It returns `false` for the data on the heap memory pointed by the owner.
+    pub fn is_within_owner(this: &ArcRef<'a, O, I, E>) -> bool {
+        offset_within(&*this.owner, this.inner).is_some()
+    }
+
     /// Apply function `f` to the inner reference.
+    #[cfg_attr(feature = "checked", track_caller)]
     pub fn map<T, F>(self, f: F) -> ArcRef<'a, O, T, E>
     where
         F: FnOnce(&'a I) -> &'a T,
@@ -110,12 +244,13 @@ where
 
         ArcRef {
             owner,
-            inner: f(inner),
+            inner: Caller::new().check(f(inner)),
             _phantom: PhantomData,
         }
     }
 
     /// Apply fallible function `f` to the inner reference.
+    #[cfg_attr(feature = "checked", track_caller)]
     pub fn try_map<Ok, Err, F>(self, f: F) -> Result<ArcRef<'a, O, Ok, E>, Err>
     where
         F: FnOnce(&'a I) -> Result<&'a Ok, Err>,
@@ -125,12 +260,13 @@ where
 
         Ok(ArcRef {
             owner,
-            inner: f(inner)?,
+            inner: Caller::new().check(f(inner)?),
             _phantom: PhantomData,
         })
     }
 
     /// Apply function `f` that returns an optional reference to the inner reference.
+    #[cfg_attr(feature = "checked", track_caller)]
     pub fn filter_map<T, F>(self, f: F) -> Option<ArcRef<'a, O, T, E>>
     where
         F: FnOnce(&'a I) -> Option<&'a T>,
@@ -140,30 +276,53 @@ where
 
         Some(ArcRef {
             owner,
-            inner: f(inner)?,
+            inner: Caller::new().check(f(inner)?),
             _phantom: PhantomData,
         })
     }
 
     /// Flatten the wrapped iterable inner reference into an iterator of wrapped items.
+    #[cfg_attr(feature = "checked", track_caller)]
     pub fn flatten<T>(self) -> impl Iterator<Item = ArcRef<'a, O, T, E>>
     where
         &'a I: IntoIterator<Item = &'a T>,
         T: 'a + ?Sized,
     {
         let Self { owner, inner, .. } = self;
+        let caller = Caller::new();
+
         inner.into_iter().map(move |item| {
             let owner = owner.clone();
 
             ArcRef {
                 owner,
-                inner: item,
+                inner: caller.check(item),
                 _phantom: PhantomData,
             }
         })
     }
 
+    /// Iterate over views to the items of the iterable inner reference.
+    ///
+    /// The views borrow the owner from this reference without increasing the reference count.
+    pub fn iter_views<'v, T>(
+        this: &'v ArcRef<'a, O, I, E>,
+    ) -> impl Iterator<Item = ArcRefView<'v, 'a, O, T, E>>
+    where
+        &'a I: IntoIterator<Item = &'a T>,
+        T: 'a + ?Sized,
+    {
+        let owner = &this.owner;
+
+        this.inner.into_iter().map(move |item| ArcRefView {
+            owner,
+            inner: item,
+            _phantom: PhantomData,
+        })
+    }
+
     /// Apply fucntion `f` to get an iterable type, and flatten it to an iterator of references.
+    #[cfg_attr(feature = "checked", track_caller)]
     pub fn flat_map<T, C, F>(self, f: F) -> impl Iterator<Item = ArcRef<'a, O, T, E>>
     where
         F: FnOnce(&'a I) -> C,
@@ -172,13 +331,14 @@ where
     {
         let Self { owner, inner, .. } = self;
         let iter = f(inner);
+        let caller = Caller::new();
 
         iter.into_iter().map(move |item| {
             let owner = owner.clone();
 
             ArcRef {
                 owner,
-                inner: item,
+                inner: caller.check(item),
                 _phantom: PhantomData,
             }
         })
@@ -213,6 +373,20 @@ where
         }
     }
 
+    /// Change the owner type to [Any] trait object, which is not required to be [Send] or [Sync].
+    pub fn into_any_owner_local(from: ArcRef<'a, O, I, E>) -> ArcRef<'a, dyn Any + 'static, I, E>
+    where
+        O: 'static,
+    {
+        let Self { owner, inner, .. } = from;
+
+        ArcRef {
+            inner,
+            owner,
+            _phantom: PhantomData,
+        }
+    }
+
     /// Unwrap the owner if strong count is one.
     pub fn try_unwrap_owner(from: ArcRef<'a, O, I, E>) -> Result<O, Self> {
         let Self { owner, inner, .. } = from;
@@ -242,6 +416,19 @@ where
     I: ?Sized,
     E: EqKind,
 {
+    /// Get the [TypeId] of the concrete owner type.
+    pub fn owner_type_id(this: &Self) -> TypeId {
+        (*this.owner).type_id()
+    }
+
+    /// Get the reference to the owner if the owner is of type `O`.
+    pub fn owner_downcast_ref<O>(this: &Self) -> Option<&O>
+    where
+        O: 'static,
+    {
+        this.owner.downcast_ref()
+    }
+
     /// Downcast the [Any]-trait object owner to concrete type.
     pub fn downcast_owner<O>(this: Self) -> Result<ArcRef<'a, O, I, E>, Self>
     where
@@ -264,6 +451,77 @@ where
     }
 }
 
+impl<'a, I, E> ArcRef<'a, dyn Any + 'static, I, E>
+where
+    I: ?Sized,
+    E: EqKind,
+{
+    /// Get the [TypeId] of the concrete owner type.
+    pub fn owner_type_id(this: &Self) -> TypeId {
+        (*this.owner).type_id()
+    }
+
+    /// Get the reference to the owner if the owner is of type `O`.
+    pub fn owner_downcast_ref<O>(this: &Self) -> Option<&O>
+    where
+        O: 'static,
+    {
+        this.owner.downcast_ref()
+    }
+
+    /// Downcast the [Any] trait object owner to concrete type.
+    pub fn downcast_owner_local<O>(this: Self) -> Result<ArcRef<'a, O, I, E>, Self>
+    where
+        O: 'static,
+    {
+        let Self { owner, inner, .. } = this;
+
+        if owner.is::<O>() {
+            // the pointer is cast to the concrete type which is checked above
+            let owner = unsafe { Arc::from_raw(Arc::into_raw(owner) as *const O) };
+
+            Ok(ArcRef {
+                owner,
+                inner,
+                _phantom: PhantomData,
+            })
+        } else {
+            Err(ArcRef {
+                owner,
+                inner,
+                _phantom: PhantomData,
+            })
+        }
+    }
+}
+
+impl<'a, O, E> ArcRef<'a, O, dyn Any + 'static, E>
+where
+    O: ?Sized,
+    E: EqKind,
+{
+    /// Downcast the [Any] trait object data to concrete type.
+    pub fn downcast_inner<T>(this: Self) -> Result<ArcRef<'a, O, T, E>, Self>
+    where
+        T: 'static,
+    {
+        let Self { owner, inner, .. } = this;
+
+        match inner.downcast_ref() {
+            Some(inner) => Ok(ArcRef {
+                owner,
+                inner,
+                _phantom: PhantomData,
+            }),
+            None => Err(ArcRef {
+                owner,
+                inner,
+                _phantom: PhantomData,
+            }),
+        }
+    }
+}
+
 impl<'a, O, I, E> Clone for ArcRef<'a, O, I, E>
 where
     O: ?Sized,
@@ -383,7 +641,7 @@ where
     I: ?Sized,
 {
     fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
-        (self.inner as *const I).partial_cmp(&(other.inner as *const I))
+        Some(self.cmp(other))
     }
 }
 
@@ -392,6 +650,7 @@ where
     O: ?Sized,
     I: ?Sized,
 {
+    #[allow(ambiguous_wide_pointer_comparisons)]
     fn cmp(&self, other: &Self) -> cmp::Ordering {
         (self.inner as *const I).cmp(&(other.inner as *const I))
     }
@@ -421,17 +680,26 @@ where
     }
 }
 
-impl<'a, O, I, E> Borrow<I> for ArcRef<'a, O, I, E>
+impl<'a, O, I> Borrow<I> for ArcRef<'a, O, I, ByContent>
 where
     O: ?Sized,
     I: ?Sized,
-    E: EqKind,
 {
     fn borrow(&self) -> &I {
         self.deref()
     }
 }
 
+impl<'a, O, I> Borrow<Addr<I>> for ArcRef<'a, O, I, ByAddress>
+where
+    O: ?Sized,
+    I: ?Sized,
+{
+    fn borrow(&self) -> &Addr<I> {
+        Addr::new(self.inner)
+    }
+}
+
 impl<'a, O, I, E> Deref for ArcRef<'a, O, I, E>
 where
     O: ?Sized,
@@ -463,3 +731,13 @@ where
         }
     }
 }
+
+#[cfg(feature = "unsize")]
+impl<'a, O, I, U, E> CoerceUnsized<ArcRef<'a, O, U, E>> for ArcRef<'a, O, I, E>
+where
+    O: ?Sized,
+    I: ?Sized + Unsize<U>,
+    U: ?Sized,
+    E: EqKind,
+{
+}
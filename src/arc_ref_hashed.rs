@@ -0,0 +1,399 @@
+use crate::{arc_ref::ArcRef, marker::*};
+use std::{
+    borrow::Borrow,
+    cmp,
+    collections::hash_map::DefaultHasher,
+    fmt,
+    fmt::{Debug, Display},
+    hash::{Hash, Hasher},
+    ops::Deref,
+    sync::Arc,
+};
+
+/// Content ordered reference to data within an owner in [Arc], which caches the hash of the data.
+///
+/// The hash is computed once when the reference is built or projected. [Hash] feeds the
+/// cached value to the hasher, and [PartialEq] compares the cached hashes before the content,
+/// which speeds up repeated lookups of large keys in hash maps.
+///
+/// Unlike `ArcRefC`, it does not implement [Borrow]`<I>`, because the cached hash differs
+/// from the hash of the data itself, which would break the contract of [Borrow]. It
+/// implements [Borrow]`<Hashed<I>>` instead, so that hash maps are queried by a [Hashed]
+/// key, which hashes and compares the same way without an owner.
+pub struct ArcRefHashed<'a, O, I = O>
+where
+    O: ?Sized,
+    I: ?Sized,
+{
+    key: Hashed<'a, I>,
+    inner: ArcRef<'a, O, I, ByContent>,
+}
+
+/// Borrowed data along with its hash, which is the lookup key for [ArcRefHashed].
+pub struct Hashed<'k, I>
+where
+    I: ?Sized,
+{
+    hash: u64,
+    data: &'k I,
+}
+
+impl<'k, I> Hashed<'k, I>
+where
+    I: ?Sized + Hash,
+{
+    /// Build from `data` and compute its hash.
+    pub fn new(data: &'k I) -> Self {
+        let mut hasher = DefaultHasher::new();
+        data.hash(&mut hasher);
+
+        Self {
+            hash: hasher.finish(),
+            data,
+        }
+    }
+}
+
+impl<'k, I> Hashed<'k, I>
+where
+    I: ?Sized,
+{
+    /// Get the reference to the data.
+    pub fn data(&self) -> &'k I {
+        self.data
+    }
+
+    /// Get the cached hash of the data.
+    pub fn cached_hash(&self) -> u64 {
+        self.hash
+    }
+}
+
+impl<'a, O, I> ArcRefHashed<'a, O, I>
+where
+    O: ?Sized,
+    I: ?Sized + Hash,
+{
+    /// Build from an [ArcRef] and compute the hash of the data.
+    pub fn from_arc_ref(from: ArcRef<'a, O, I, ByContent>) -> Self {
+        Self {
+            key: Hashed::new(from.inner),
+            inner: from,
+        }
+    }
+}
+
+impl<'a, O> ArcRefHashed<'a, O, O>
+where
+    O: Hash,
+{
+    /// Build from an owner.
+    pub fn new(owner: O) -> Self {
+        Self::from_arc_ref(ArcRef::new(owner))
+    }
+}
+
+impl<'a, O, I> ArcRefHashed<'a, O, I>
+where
+    O: ?Sized,
+    I: ?Sized,
+{
+    /// Discard the cached hash and return the [ArcRef].
+    pub fn into_arc_ref(from: ArcRefHashed<'a, O, I>) -> ArcRef<'a, O, I, ByContent> {
+        from.inner
+    }
+
+    /// Get the reference to the [ArcRef].
+    pub fn as_arc_ref<'b>(this: &'b ArcRefHashed<'a, O, I>) -> &'b ArcRef<'a, O, I, ByContent> {
+        &this.inner
+    }
+
+    /// Get the cached hash of the data.
+    pub fn cached_hash(this: &ArcRefHashed<'a, O, I>) -> u64 {
+        this.key.hash
+    }
+
+    /// Get the reference to the owner.
+    pub fn owner(this: &'a ArcRefHashed<'a, O, I>) -> &'a O {
+        ArcRef::owner(&this.inner)
+    }
+
+    /// Get the strong count on the owner.
+    pub fn strong_count(this: &ArcRefHashed<'a, O, I>) -> usize {
+        ArcRef::strong_count(&this.inner)
+    }
+
+    /// Apply function `f` to the inner reference and compute the hash of the new data.
+    #[cfg_attr(feature = "checked", track_caller)]
+    pub fn map<T, F>(self, f: F) -> ArcRefHashed<'a, O, T>
+    where
+        F: FnOnce(&'a I) -> &'a T,
+        T: ?Sized + Hash,
+    {
+        ArcRefHashed::from_arc_ref(self.inner.map(f))
+    }
+
+    /// Apply fallible function `f` to the inner reference and compute the hash of the new data.
+    #[cfg_attr(feature = "checked", track_caller)]
+    pub fn try_map<Ok, Err, F>(self, f: F) -> Result<ArcRefHashed<'a, O, Ok>, Err>
+    where
+        F: FnOnce(&'a I) -> Result<&'a Ok, Err>,
+        Ok: ?Sized + Hash,
+    {
+        Ok(ArcRefHashed::from_arc_ref(self.inner.try_map(f)?))
+    }
+
+    /// Apply function `f` that returns an optional reference to the inner reference, and
+    /// compute the hash of the new data.
+    #[cfg_attr(feature = "checked", track_caller)]
+    pub fn filter_map<T, F>(self, f: F) -> Option<ArcRefHashed<'a, O, T>>
+    where
+        F: FnOnce(&'a I) -> Option<&'a T>,
+        T: ?Sized + Hash,
+    {
+        Some(ArcRefHashed::from_arc_ref(self.inner.filter_map(f)?))
+    }
+
+    /// Flatten the wrapped iterable inner reference into an iterator of wrapped items
+    /// with cached hashes.
+    #[cfg_attr(feature = "checked", track_caller)]
+    pub fn flatten<T>(self) -> impl Iterator<Item = ArcRefHashed<'a, O, T>>
+    where
+        &'a I: IntoIterator<Item = &'a T>,
+        T: 'a + ?Sized + Hash,
+    {
+        self.inner.flatten().map(ArcRefHashed::from_arc_ref)
+    }
+
+    /// Apply fucntion `f` to get an iterable type, and flatten it to an iterator of
+    /// references with cached hashes.
+    #[cfg_attr(feature = "checked", track_caller)]
+    pub fn flat_map<T, C, F>(self, f: F) -> impl Iterator<Item = ArcRefHashed<'a, O, T>>
+    where
+        F: FnOnce(&'a I) -> C,
+        C: IntoIterator<Item = &'a T>,
+        T: 'a + ?Sized + Hash,
+    {
+        self.inner.flat_map(f).map(ArcRefHashed::from_arc_ref)
+    }
+}
+
+impl<'a, O, I> Clone for ArcRefHashed<'a, O, I>
+where
+    O: ?Sized,
+    I: ?Sized,
+{
+    fn clone(&self) -> Self {
+        Self {
+            key: self.key,
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<'a, O, I> Debug for ArcRefHashed<'a, O, I>
+where
+    O: ?Sized,
+    I: ?Sized,
+    I: Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        self.deref().fmt(f)
+    }
+}
+
+impl<'a, O, I> Display for ArcRefHashed<'a, O, I>
+where
+    O: ?Sized,
+    I: ?Sized,
+    I: Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        self.deref().fmt(f)
+    }
+}
+
+impl<'a, O, I> PartialEq<Self> for ArcRefHashed<'a, O, I>
+where
+    O: ?Sized,
+    I: ?Sized,
+    I: PartialEq<I>,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.key.eq(&other.key)
+    }
+}
+
+impl<'a, O, I> Eq for ArcRefHashed<'a, O, I>
+where
+    I: Eq,
+    O: ?Sized,
+    I: ?Sized,
+{
+}
+
+impl<'a, O, I> PartialOrd<Self> for ArcRefHashed<'a, O, I>
+where
+    O: ?Sized,
+    I: ?Sized,
+    I: PartialOrd<I>,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
+        self.deref().partial_cmp(other.deref())
+    }
+}
+
+impl<'a, O, I> Ord for ArcRefHashed<'a, O, I>
+where
+    O: ?Sized,
+    I: ?Sized,
+    I: Ord,
+{
+    fn cmp(&self, other: &Self) -> cmp::Ordering {
+        self.deref().cmp(other.deref())
+    }
+}
+
+impl<'a, O, I> Hash for ArcRefHashed<'a, O, I>
+where
+    O: ?Sized,
+    I: ?Sized,
+{
+    fn hash<H>(&self, state: &mut H)
+    where
+        H: Hasher,
+    {
+        self.key.hash(state);
+    }
+}
+
+impl<'a, O, I> AsRef<I> for ArcRefHashed<'a, O, I>
+where
+    O: ?Sized,
+    I: ?Sized,
+{
+    fn as_ref(&self) -> &I {
+        self.deref()
+    }
+}
+
+impl<'a, O, I> Borrow<Hashed<'a, I>> for ArcRefHashed<'a, O, I>
+where
+    O: ?Sized,
+    I: ?Sized,
+{
+    fn borrow(&self) -> &Hashed<'a, I> {
+        &self.key
+    }
+}
+
+impl<'a, O, I> Deref for ArcRefHashed<'a, O, I>
+where
+    O: ?Sized,
+    I: ?Sized,
+{
+    type Target = I;
+
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl<'a, O, I> From<ArcRef<'a, O, I, ByContent>> for ArcRefHashed<'a, O, I>
+where
+    O: ?Sized,
+    I: ?Sized + Hash,
+{
+    fn from(from: ArcRef<'a, O, I, ByContent>) -> Self {
+        Self::from_arc_ref(from)
+    }
+}
+
+impl<'a, O, I> From<ArcRefHashed<'a, O, I>> for ArcRef<'a, O, I, ByContent>
+where
+    O: ?Sized,
+    I: ?Sized,
+{
+    fn from(from: ArcRefHashed<'a, O, I>) -> Self {
+        ArcRefHashed::into_arc_ref(from)
+    }
+}
+
+impl<'a, O> From<Arc<O>> for ArcRefHashed<'a, O, O>
+where
+    O: ?Sized + Hash,
+{
+    fn from(owner: Arc<O>) -> Self {
+        Self::from_arc_ref(owner.into())
+    }
+}
+
+impl<'k, I> Clone for Hashed<'k, I>
+where
+    I: ?Sized,
+{
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'k, I> Copy for Hashed<'k, I> where I: ?Sized {}
+
+impl<'k, I> Debug for Hashed<'k, I>
+where
+    I: ?Sized,
+    I: Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        self.data.fmt(f)
+    }
+}
+
+impl<'k, I> PartialEq<Self> for Hashed<'k, I>
+where
+    I: ?Sized,
+    I: PartialEq<I>,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.hash == other.hash && self.data.eq(other.data)
+    }
+}
+
+impl<'k, I> Eq for Hashed<'k, I>
+where
+    I: Eq,
+    I: ?Sized,
+{
+}
+
+impl<'k, I> PartialOrd<Self> for Hashed<'k, I>
+where
+    I: ?Sized,
+    I: PartialOrd<I>,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
+        self.data.partial_cmp(other.data)
+    }
+}
+
+impl<'k, I> Ord for Hashed<'k, I>
+where
+    I: ?Sized,
+    I: Ord,
+{
+    fn cmp(&self, other: &Self) -> cmp::Ordering {
+        self.data.cmp(other.data)
+    }
+}
+
+impl<'k, I> Hash for Hashed<'k, I>
+where
+    I: ?Sized,
+{
+    fn hash<H>(&self, state: &mut H)
+    where
+        H: Hasher,
+    {
+        state.write_u64(self.hash);
+    }
+}
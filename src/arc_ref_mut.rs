@@ -0,0 +1,404 @@
+use crate::{addr::Addr, arc_ref::ArcRef, marker::*, utils::arc_is_unique};
+use std::{
+    borrow::Borrow,
+    cmp, fmt,
+    fmt::{Debug, Display},
+    hash::{Hash, Hasher},
+    marker::PhantomData,
+    ops::Deref,
+    ptr::{self, NonNull},
+    sync::Arc,
+};
+
+/// Content ordered reference to data within an owner in [Arc] obtained by mutable projections.
+pub type ArcRefMutC<'a, O, I = O> = ArcRefMut<'a, O, I, ByContent>;
+
+/// Pointer address ordered reference to data within an owner in [Arc] obtained by mutable projections.
+pub type ArcRefMutA<'a, O, I = O> = ArcRefMut<'a, O, I, ByAddress>;
+
+/// Reference to data within an owner in [Arc] obtained by mutable projections.
+///
+/// [ArcRef] is projected by shared references, so the data can never be mutated through it.
+/// This type remembers the inner reference is projected from a mutable borrow of the owner.
+/// It can be cloned and shared like [ArcRef], and the data can be mutated by
+/// [ArcRefMut::get_mut()] once the reference becomes unique.
+pub struct ArcRefMut<'a, O, I, E>
+where
+    O: ?Sized,
+    I: ?Sized,
+    E: EqKind,
+{
+    // inner goes before owner so that inner drops before owner
+    pub(crate) _phantom: PhantomData<(&'a mut I, E)>,
+    pub(crate) inner: NonNull<I>,
+    pub(crate) owner: Arc<O>,
+}
+
+unsafe impl<'a, O, I, E> Send for ArcRefMut<'a, O, I, E>
+where
+    O: ?Sized + Send + Sync,
+    I: ?Sized + Send + Sync,
+    E: EqKind,
+{
+}
+
+unsafe impl<'a, O, I, E> Sync for ArcRefMut<'a, O, I, E>
+where
+    O: ?Sized + Send + Sync,
+    I: ?Sized + Send + Sync,
+    E: EqKind,
+{
+}
+
+impl<'a, O, E> ArcRefMut<'a, O, O, E>
+where
+    O: ?Sized,
+    E: EqKind,
+{
+    /// Build from owner data in [Arc].
+    pub fn from_arc(owner: Arc<O>) -> Self {
+        owner.into()
+    }
+}
+
+impl<'a, O, I, E> ArcRefMut<'a, O, I, E>
+where
+    O: ?Sized,
+    I: ?Sized,
+    E: EqKind,
+{
+    /// Discard the inner reference and return the owner in [Arc].
+    pub fn into_arc(from: ArcRefMut<'a, O, I, E>) -> Arc<O> {
+        let Self { owner, .. } = from;
+        owner
+    }
+
+    /// Convert to [ArcRef]. The data cannot be mutated anymore after conversion.
+    pub fn into_arc_ref(this: ArcRefMut<'a, O, I, E>) -> ArcRef<'a, O, I, E> {
+        let Self { owner, inner, .. } = this;
+
+        ArcRef {
+            owner,
+            inner: unsafe { inner.as_ref() },
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Reset the inner reference to the owner.
+    pub fn into_owner_ref(this: ArcRefMut<'a, O, I, E>) -> ArcRefMut<'a, O, O, E> {
+        let Self { owner, .. } = this;
+        owner.into()
+    }
+
+    /// Get the reference to the owner.
+    pub fn owner(this: &'a ArcRefMut<'a, O, I, E>) -> &'a O {
+        &this.owner
+    }
+
+    /// Get the strong count on the owner.
+    pub fn strong_count(this: &ArcRefMut<'a, O, I, E>) -> usize {
+        Arc::strong_count(&this.owner)
+    }
+
+    /// Get the weak count on the owner.
+    pub fn weak_count(this: &ArcRefMut<'a, O, I, E>) -> usize {
+        Arc::weak_count(&this.owner)
+    }
+
+    /// Get the mutable reference to the data if strong count is one and weak count is zero.
+    pub fn get_mut(this: &mut Self) -> Option<&mut I> {
+        if arc_is_unique(&mut this.owner) {
+            Some(unsafe { this.inner.as_mut() })
+        } else {
+            None
+        }
+    }
+
+    /// Apply function `f` to the inner reference if strong count is one and weak count is zero.
+    ///
+    /// It returns the unchanged reference if the owner is shared.
+    pub fn map<T, F>(mut self, f: F) -> Result<ArcRefMut<'a, O, T, E>, Self>
+    where
+        F: FnOnce(&'a mut I) -> &'a mut T,
+        T: ?Sized,
+    {
+        if !arc_is_unique(&mut self.owner) {
+            return Err(self);
+        }
+
+        let Self { owner, inner, .. } = self;
+        let inner = f(unsafe { &mut *inner.as_ptr() });
+
+        Ok(ArcRefMut {
+            owner,
+            inner: NonNull::from(inner),
+            _phantom: PhantomData,
+        })
+    }
+
+    /// Apply function `f` that returns an optional reference to the inner reference if strong
+    /// count is one and weak count is zero.
+    ///
+    /// It returns the unchanged reference if the owner is shared.
+    pub fn filter_map<T, F>(mut self, f: F) -> Result<Option<ArcRefMut<'a, O, T, E>>, Self>
+    where
+        F: FnOnce(&'a mut I) -> Option<&'a mut T>,
+        T: ?Sized,
+    {
+        if !arc_is_unique(&mut self.owner) {
+            return Err(self);
+        }
+
+        let Self { owner, inner, .. } = self;
+
+        Ok(f(unsafe { &mut *inner.as_ptr() }).map(|inner| ArcRefMut {
+            owner,
+            inner: NonNull::from(inner),
+            _phantom: PhantomData,
+        }))
+    }
+}
+
+impl<'a, O, I, E> ArcRefMut<'a, O, I, E>
+where
+    E: EqKind,
+{
+    /// Build from an owner.
+    pub fn new(owner: O) -> Self
+    where
+        Self: From<Arc<O>>,
+    {
+        Arc::new(owner).into()
+    }
+
+    /// Unwrap the owner if strong count is one.
+    pub fn try_unwrap_owner(from: ArcRefMut<'a, O, I, E>) -> Result<O, Self> {
+        let Self { owner, inner, .. } = from;
+
+        match Arc::try_unwrap(owner) {
+            Ok(owner) => Ok(owner),
+            Err(owner) => Err(Self {
+                owner,
+                inner,
+                _phantom: PhantomData,
+            }),
+        }
+    }
+
+    /// Unwrap the owner and panic if strong count is one.
+    ///
+    /// # Panic
+    /// The method panics if strong count is not 1.
+    pub fn unwrap_owner(from: ArcRefMut<'a, O, I, E>) -> O {
+        Self::try_unwrap_owner(from)
+            .unwrap_or_else(|_| panic!("unable to unwrap because strong count is greater than 1"))
+    }
+}
+
+impl<'a, O, I, E> Clone for ArcRefMut<'a, O, I, E>
+where
+    O: ?Sized,
+    I: ?Sized,
+    E: EqKind,
+{
+    /// Copy the inner reference and increase reference count to owner.
+    fn clone(&self) -> Self {
+        let Self { owner, inner, .. } = self;
+
+        Self {
+            owner: owner.clone(),
+            inner: *inner,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<'a, O, I, E> Debug for ArcRefMut<'a, O, I, E>
+where
+    O: ?Sized,
+    I: ?Sized,
+    I: Debug,
+    E: EqKind,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        self.deref().fmt(f)
+    }
+}
+
+impl<'a, O, I, E> Display for ArcRefMut<'a, O, I, E>
+where
+    O: ?Sized,
+    I: ?Sized,
+    I: Display,
+    E: EqKind,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        self.deref().fmt(f)
+    }
+}
+
+impl<'a, O, I> PartialEq<Self> for ArcRefMut<'a, O, I, ByContent>
+where
+    O: ?Sized,
+    I: ?Sized,
+    I: PartialEq<I>,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.deref().eq(other.deref())
+    }
+}
+
+impl<'a, O, I> Eq for ArcRefMut<'a, O, I, ByContent>
+where
+    I: Eq,
+    O: ?Sized,
+    I: ?Sized,
+{
+}
+
+impl<'a, O, I> PartialOrd<Self> for ArcRefMut<'a, O, I, ByContent>
+where
+    O: ?Sized,
+    I: ?Sized,
+    I: PartialOrd<I>,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
+        self.deref().partial_cmp(other.deref())
+    }
+}
+
+impl<'a, O, I> Ord for ArcRefMut<'a, O, I, ByContent>
+where
+    O: ?Sized,
+    I: ?Sized,
+    I: Ord,
+{
+    fn cmp(&self, other: &Self) -> cmp::Ordering {
+        self.deref().cmp(other.deref())
+    }
+}
+
+impl<'a, O, I> Hash for ArcRefMut<'a, O, I, ByContent>
+where
+    O: ?Sized,
+    I: ?Sized + Hash,
+{
+    fn hash<H>(&self, state: &mut H)
+    where
+        H: Hasher,
+    {
+        self.deref().hash(state);
+    }
+}
+
+impl<'a, O, I> PartialEq<Self> for ArcRefMut<'a, O, I, ByAddress>
+where
+    O: ?Sized,
+    I: ?Sized,
+{
+    fn eq(&self, other: &Self) -> bool {
+        ptr::eq(self.inner.as_ptr(), other.inner.as_ptr())
+    }
+}
+
+impl<'a, O, I> Eq for ArcRefMut<'a, O, I, ByAddress>
+where
+    O: ?Sized,
+    I: ?Sized,
+{
+}
+
+impl<'a, O, I> PartialOrd<Self> for ArcRefMut<'a, O, I, ByAddress>
+where
+    O: ?Sized,
+    I: ?Sized,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<'a, O, I> Ord for ArcRefMut<'a, O, I, ByAddress>
+where
+    O: ?Sized,
+    I: ?Sized,
+{
+    #[allow(ambiguous_wide_pointer_comparisons)]
+    fn cmp(&self, other: &Self) -> cmp::Ordering {
+        (self.inner.as_ptr() as *const I).cmp(&(other.inner.as_ptr() as *const I))
+    }
+}
+
+impl<'a, O, I> Hash for ArcRefMut<'a, O, I, ByAddress>
+where
+    O: ?Sized,
+    I: ?Sized,
+{
+    fn hash<H>(&self, state: &mut H)
+    where
+        H: Hasher,
+    {
+        ptr::hash(self.inner.as_ptr() as *const I, state);
+    }
+}
+
+impl<'a, O, I, E> AsRef<I> for ArcRefMut<'a, O, I, E>
+where
+    O: ?Sized,
+    I: ?Sized,
+    E: EqKind,
+{
+    fn as_ref(&self) -> &I {
+        self.deref()
+    }
+}
+
+impl<'a, O, I> Borrow<I> for ArcRefMut<'a, O, I, ByContent>
+where
+    O: ?Sized,
+    I: ?Sized,
+{
+    fn borrow(&self) -> &I {
+        self.deref()
+    }
+}
+
+impl<'a, O, I> Borrow<Addr<I>> for ArcRefMut<'a, O, I, ByAddress>
+where
+    O: ?Sized,
+    I: ?Sized,
+{
+    fn borrow(&self) -> &Addr<I> {
+        Addr::new(self.deref())
+    }
+}
+
+impl<'a, O, I, E> Deref for ArcRefMut<'a, O, I, E>
+where
+    O: ?Sized,
+    I: ?Sized,
+    E: EqKind,
+{
+    type Target = I;
+
+    fn deref(&self) -> &Self::Target {
+        unsafe { self.inner.as_ref() }
+    }
+}
+
+impl<'a, O, E> From<Arc<O>> for ArcRefMut<'a, O, O, E>
+where
+    O: ?Sized,
+    E: EqKind,
+{
+    fn from(owner: Arc<O>) -> Self {
+        // the pointer from Arc::as_ptr() is allowed to write once the Arc is unique
+        let inner = unsafe { NonNull::new_unchecked(Arc::as_ptr(&owner) as *mut O) };
+
+        Self {
+            inner,
+            owner,
+            _phantom: PhantomData,
+        }
+    }
+}
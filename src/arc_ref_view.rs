@@ -0,0 +1,261 @@
+use crate::{addr::Addr, arc_ref::ArcRef, marker::*};
+use std::{
+    borrow::Borrow,
+    cmp, fmt,
+    fmt::{Debug, Display},
+    hash::{Hash, Hasher},
+    marker::PhantomData,
+    ops::Deref,
+    ptr,
+    sync::Arc,
+};
+
+/// Content ordered view to data within an owner borrowed from an [ArcRef].
+pub type ArcRefViewC<'v, 'a, O, I = O> = ArcRefView<'v, 'a, O, I, ByContent>;
+
+/// Pointer address ordered view to data within an owner borrowed from an [ArcRef].
+pub type ArcRefViewA<'v, 'a, O, I = O> = ArcRefView<'v, 'a, O, I, ByAddress>;
+
+/// View to data within an owner, which borrows the owner from an [ArcRef].
+///
+/// It is created by [ArcRef::iter_views()] without touching the reference count,
+/// and it is ordered in the same way as [ArcRef]. The views to be kept are promoted
+/// to [ArcRef] by [ArcRefView::to_arc_ref()].
+pub struct ArcRefView<'v, 'a, O, I, E>
+where
+    O: ?Sized,
+    I: ?Sized,
+    E: EqKind,
+{
+    pub(crate) _phantom: PhantomData<E>,
+    pub(crate) inner: &'a I,
+    pub(crate) owner: &'v Arc<O>,
+}
+
+impl<'v, 'a, O, I, E> ArcRefView<'v, 'a, O, I, E>
+where
+    O: ?Sized,
+    I: ?Sized,
+    E: EqKind,
+{
+    /// Promote to [ArcRef] by increasing the reference count to owner.
+    pub fn to_arc_ref(this: &ArcRefView<'v, 'a, O, I, E>) -> ArcRef<'a, O, I, E> {
+        ArcRef {
+            owner: this.owner.clone(),
+            inner: this.inner,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Get the reference to the owner.
+    pub fn owner(this: &ArcRefView<'v, 'a, O, I, E>) -> &'v O {
+        this.owner
+    }
+
+    /// Apply function `f` to the inner reference.
+    pub fn map<T, F>(self, f: F) -> ArcRefView<'v, 'a, O, T, E>
+    where
+        F: FnOnce(&'a I) -> &'a T,
+        T: ?Sized,
+    {
+        let Self { owner, inner, .. } = self;
+
+        ArcRefView {
+            owner,
+            inner: f(inner),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<'v, 'a, O, I, E> Clone for ArcRefView<'v, 'a, O, I, E>
+where
+    O: ?Sized,
+    I: ?Sized,
+    E: EqKind,
+{
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'v, 'a, O, I, E> Copy for ArcRefView<'v, 'a, O, I, E>
+where
+    O: ?Sized,
+    I: ?Sized,
+    E: EqKind,
+{
+}
+
+impl<'v, 'a, O, I, E> Debug for ArcRefView<'v, 'a, O, I, E>
+where
+    O: ?Sized,
+    I: ?Sized,
+    I: Debug,
+    E: EqKind,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        self.inner.fmt(f)
+    }
+}
+
+impl<'v, 'a, O, I, E> Display for ArcRefView<'v, 'a, O, I, E>
+where
+    O: ?Sized,
+    I: ?Sized,
+    I: Display,
+    E: EqKind,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        self.inner.fmt(f)
+    }
+}
+
+impl<'v, 'a, O, I> PartialEq<Self> for ArcRefView<'v, 'a, O, I, ByContent>
+where
+    O: ?Sized,
+    I: ?Sized,
+    I: PartialEq<I>,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.inner.eq(other.inner)
+    }
+}
+
+impl<'v, 'a, O, I> Eq for ArcRefView<'v, 'a, O, I, ByContent>
+where
+    I: Eq,
+    O: ?Sized,
+    I: ?Sized,
+{
+}
+
+impl<'v, 'a, O, I> PartialOrd<Self> for ArcRefView<'v, 'a, O, I, ByContent>
+where
+    O: ?Sized,
+    I: ?Sized,
+    I: PartialOrd<I>,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
+        self.inner.partial_cmp(other.inner)
+    }
+}
+
+impl<'v, 'a, O, I> Ord for ArcRefView<'v, 'a, O, I, ByContent>
+where
+    O: ?Sized,
+    I: ?Sized,
+    I: Ord,
+{
+    fn cmp(&self, other: &Self) -> cmp::Ordering {
+        self.inner.cmp(other.inner)
+    }
+}
+
+impl<'v, 'a, O, I> Hash for ArcRefView<'v, 'a, O, I, ByContent>
+where
+    O: ?Sized,
+    I: ?Sized + Hash,
+{
+    fn hash<H>(&self, state: &mut H)
+    where
+        H: Hasher,
+    {
+        self.inner.hash(state);
+    }
+}
+
+impl<'v, 'a, O, I> PartialEq<Self> for ArcRefView<'v, 'a, O, I, ByAddress>
+where
+    O: ?Sized,
+    I: ?Sized,
+{
+    fn eq(&self, other: &Self) -> bool {
+        ptr::eq(self.inner as *const I, other.inner as *const I)
+    }
+}
+
+impl<'v, 'a, O, I> Eq for ArcRefView<'v, 'a, O, I, ByAddress>
+where
+    O: ?Sized,
+    I: ?Sized,
+{
+}
+
+impl<'v, 'a, O, I> PartialOrd<Self> for ArcRefView<'v, 'a, O, I, ByAddress>
+where
+    O: ?Sized,
+    I: ?Sized,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<'v, 'a, O, I> Ord for ArcRefView<'v, 'a, O, I, ByAddress>
+where
+    O: ?Sized,
+    I: ?Sized,
+{
+    #[allow(ambiguous_wide_pointer_comparisons)]
+    fn cmp(&self, other: &Self) -> cmp::Ordering {
+        (self.inner as *const I).cmp(&(other.inner as *const I))
+    }
+}
+
+impl<'v, 'a, O, I> Hash for ArcRefView<'v, 'a, O, I, ByAddress>
+where
+    O: ?Sized,
+    I: ?Sized,
+{
+    fn hash<H>(&self, state: &mut H)
+    where
+        H: Hasher,
+    {
+        ptr::hash(self.inner as *const I, state);
+    }
+}
+
+impl<'v, 'a, O, I, E> AsRef<I> for ArcRefView<'v, 'a, O, I, E>
+where
+    O: ?Sized,
+    I: ?Sized,
+    E: EqKind,
+{
+    fn as_ref(&self) -> &I {
+        self.deref()
+    }
+}
+
+impl<'v, 'a, O, I> Borrow<I> for ArcRefView<'v, 'a, O, I, ByContent>
+where
+    O: ?Sized,
+    I: ?Sized,
+{
+    fn borrow(&self) -> &I {
+        self.deref()
+    }
+}
+
+impl<'v, 'a, O, I> Borrow<Addr<I>> for ArcRefView<'v, 'a, O, I, ByAddress>
+where
+    O: ?Sized,
+    I: ?Sized,
+{
+    fn borrow(&self) -> &Addr<I> {
+        Addr::new(self.inner)
+    }
+}
+
+impl<'v, 'a, O, I, E> Deref for ArcRefView<'v, 'a, O, I, E>
+where
+    O: ?Sized,
+    I: ?Sized,
+    E: EqKind,
+{
+    type Target = I;
+
+    fn deref(&self) -> &Self::Target {
+        self.inner
+    }
+}
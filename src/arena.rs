@@ -0,0 +1,83 @@
+use crate::{arc_ref::ArcRef, marker::*};
+use std::{
+    cmp, fmt,
+    fmt::Debug,
+    marker::PhantomData,
+    sync::{Arc, Mutex, MutexGuard},
+};
+
+/// Append-only arena, which hands out [ArcRef]s to the allocated values.
+///
+/// The values are stored in chunks that are never re-allocated, so that the allocated
+/// values stay at the same address. The references to the allocations share the ownership
+/// of the arena, and the arena with all values is freed when the last reference drops.
+pub struct Arena<T> {
+    chunks: Mutex<Vec<Vec<T>>>,
+}
+
+impl<T> Arena<T> {
+    /// Create an empty arena.
+    pub fn new() -> Self {
+        Self::with_capacity(0)
+    }
+
+    /// Create an empty arena with space for `capacity` values in the first chunk.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            chunks: Mutex::new(vec![Vec::with_capacity(capacity)]),
+        }
+    }
+
+    /// Move `value` into the arena and return the reference to it.
+    pub fn alloc<'a, E>(self: &Arc<Self>, value: T) -> ArcRef<'a, Arena<T>, T, E>
+    where
+        E: EqKind,
+    {
+        let mut chunks = self.lock();
+        let mut chunk = chunks.last_mut().unwrap();
+
+        if chunk.len() == chunk.capacity() {
+            // allocate a new chunk instead of growing the full one to keep values in place
+            let capacity = cmp::max(chunk.capacity() * 2, 1);
+            chunks.push(Vec::with_capacity(capacity));
+            chunk = chunks.last_mut().unwrap();
+        }
+
+        chunk.push(value);
+        let inner = chunk.last().unwrap() as *const T;
+
+        ArcRef {
+            owner: self.clone(),
+            // re-borrow to obtain 'a lifetime
+            inner: unsafe { &*inner },
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Get the number of allocated values.
+    pub fn len(&self) -> usize {
+        self.lock().iter().map(|chunk| chunk.len()).sum()
+    }
+
+    /// Check if no value is allocated.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Vec<Vec<T>>> {
+        // the chunks are always consistent, so a poisoned lock is fine to use
+        self.chunks.lock().unwrap_or_else(|err| err.into_inner())
+    }
+}
+
+impl<T> Default for Arena<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Debug for Arena<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        f.debug_struct("Arena").field("len", &self.len()).finish()
+    }
+}
@@ -0,0 +1,245 @@
+use crate::{arc_ref::ArcRef, marker::*};
+use std::{
+    fmt,
+    fmt::Debug,
+    marker::PhantomData,
+    ptr,
+    sync::{
+        atomic::{AtomicPtr, AtomicUsize, Ordering},
+        Arc, Mutex,
+    },
+    thread,
+};
+
+/// Content compared atomically swappable [ArcRef].
+pub type AtomicArcRefC<'a, O, I = O> = AtomicArcRef<'a, O, I, ByContent>;
+
+/// Pointer address compared atomically swappable [ArcRef].
+pub type AtomicArcRefA<'a, O, I = O> = AtomicArcRef<'a, O, I, ByAddress>;
+
+/// Atomically swappable [ArcRef].
+///
+/// The owner and the inner reference are swapped together as a whole. Loading never
+/// waits for writers. Storing waits for the loads that started before it to finish
+/// before releasing the previous reference, while the loads that start later are counted
+/// separately, so that a steady stream of loads does not starve the writers.
+pub struct AtomicArcRef<'a, O, I, E>
+where
+    O: ?Sized,
+    I: ?Sized,
+    E: EqKind,
+{
+    _phantom: PhantomData<ArcRef<'a, O, I, E>>,
+    current: AtomicPtr<ArcRef<'a, O, I, E>>,
+    /// The readers counted by the parity of the epoch they entered in.
+    readers: [AtomicUsize; 2],
+    epoch: AtomicUsize,
+    /// Serializes the writers waiting for the readers.
+    release: Mutex<()>,
+}
+
+impl<'a, O, I, E> AtomicArcRef<'a, O, I, E>
+where
+    O: ?Sized,
+    I: ?Sized,
+    E: EqKind,
+{
+    /// Build from an initial reference.
+    pub fn new(value: ArcRef<'a, O, I, E>) -> Self {
+        Self {
+            current: AtomicPtr::new(Box::into_raw(Box::new(value))),
+            readers: [AtomicUsize::new(0), AtomicUsize::new(0)],
+            epoch: AtomicUsize::new(0),
+            release: Mutex::new(()),
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Get a copy of the current reference.
+    pub fn load(&self) -> ArcRef<'a, O, I, E> {
+        let _guard = ReadGuard::new(&self.readers, &self.epoch);
+        let current = self.current.load(Ordering::SeqCst);
+
+        // the value is not freed until the readers leave
+        unsafe { (*current).clone() }
+    }
+
+    /// Replace the current reference with `value`.
+    pub fn store(&self, value: ArcRef<'a, O, I, E>) {
+        self.swap(value);
+    }
+
+    /// Replace the current reference with `value` and return the previous one.
+    pub fn swap(&self, value: ArcRef<'a, O, I, E>) -> ArcRef<'a, O, I, E> {
+        let new = Box::into_raw(Box::new(value));
+        let prev = self.current.swap(new, Ordering::SeqCst);
+        unsafe { self.release(prev) }
+    }
+
+    /// Replace the current reference with `new` if it is equal to `current`.
+    ///
+    /// The references are compared by the ordering marker `E`, that is, by content for
+    /// [ByContent] and by address for [ByAddress]. It returns the previous reference on
+    /// success, or gives back `new` on failure.
+    pub fn compare_and_swap(
+        &self,
+        current: &ArcRef<'a, O, I, E>,
+        new: ArcRef<'a, O, I, E>,
+    ) -> Result<ArcRef<'a, O, I, E>, ArcRef<'a, O, I, E>>
+    where
+        ArcRef<'a, O, I, E>: PartialEq,
+    {
+        let new = Box::into_raw(Box::new(new));
+
+        let prev = loop {
+            // compare outside the guard, since the comparison runs user code
+            let snapshot = self.load();
+            if snapshot != *current {
+                break None;
+            }
+
+            let _guard = ReadGuard::new(&self.readers, &self.epoch);
+            let prev = self.current.load(Ordering::SeqCst);
+
+            // the guard keeps `prev` from being freed, so its address is not reused
+            if !is_same(unsafe { &*prev }, &snapshot) {
+                continue;
+            }
+
+            if self
+                .current
+                .compare_exchange(prev, new, Ordering::SeqCst, Ordering::SeqCst)
+                .is_ok()
+            {
+                break Some(prev);
+            }
+        };
+
+        match prev {
+            Some(prev) => Ok(unsafe { self.release(prev) }),
+            None => Err(unsafe { *Box::from_raw(new) }),
+        }
+    }
+
+    /// Update the reference by `f` until no other update intervenes, and return the
+    /// previous reference.
+    ///
+    /// The function `f` may be called multiple times.
+    pub fn rcu<F>(&self, mut f: F) -> ArcRef<'a, O, I, E>
+    where
+        F: FnMut(&ArcRef<'a, O, I, E>) -> ArcRef<'a, O, I, E>,
+        ArcRef<'a, O, I, E>: PartialEq,
+    {
+        let mut prev = self.load();
+
+        loop {
+            let new = f(&prev);
+
+            match self.compare_and_swap(&prev, new) {
+                Ok(prev) => return prev,
+                Err(_) => prev = self.load(),
+            }
+        }
+    }
+
+    /// Take the current reference.
+    pub fn into_inner(self) -> ArcRef<'a, O, I, E> {
+        let current = self.current.swap(ptr::null_mut(), Ordering::SeqCst);
+        unsafe { *Box::from_raw(current) }
+    }
+
+    /// Wait for the readers to leave and take the swapped out reference.
+    ///
+    /// # Safety
+    /// The pointer `prev` must be swapped out from `self.current`.
+    unsafe fn release(&self, prev: *mut ArcRef<'a, O, I, E>) -> ArcRef<'a, O, I, E> {
+        // the readers of the past epochs have left once the lock is taken
+        let _lock = self.release.lock().unwrap_or_else(|err| err.into_inner());
+
+        // the readers entering from now on see the new reference
+        let epoch = self.epoch.fetch_add(1, Ordering::SeqCst);
+        let readers = &self.readers[epoch % 2];
+
+        while readers.load(Ordering::SeqCst) != 0 {
+            thread::yield_now();
+        }
+        *Box::from_raw(prev)
+    }
+}
+
+impl<'a, O, I, E> Drop for AtomicArcRef<'a, O, I, E>
+where
+    O: ?Sized,
+    I: ?Sized,
+    E: EqKind,
+{
+    fn drop(&mut self) {
+        let current = *self.current.get_mut();
+
+        if !current.is_null() {
+            unsafe {
+                drop(Box::from_raw(current));
+            }
+        }
+    }
+}
+
+impl<'a, O, I, E> Debug for AtomicArcRef<'a, O, I, E>
+where
+    O: ?Sized,
+    I: ?Sized,
+    I: Debug,
+    E: EqKind,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        self.load().fmt(f)
+    }
+}
+
+impl<'a, O, I, E> From<ArcRef<'a, O, I, E>> for AtomicArcRef<'a, O, I, E>
+where
+    O: ?Sized,
+    I: ?Sized,
+    E: EqKind,
+{
+    fn from(value: ArcRef<'a, O, I, E>) -> Self {
+        Self::new(value)
+    }
+}
+
+/// Check if both references share the same owner and inner reference.
+fn is_same<O, I, E>(lhs: &ArcRef<'_, O, I, E>, rhs: &ArcRef<'_, O, I, E>) -> bool
+where
+    O: ?Sized,
+    I: ?Sized,
+    E: EqKind,
+{
+    Arc::ptr_eq(&lhs.owner, &rhs.owner) && ptr::eq(lhs.inner as *const I, rhs.inner as *const I)
+}
+
+/// Marks a reader of the current reference until it drops.
+struct ReadGuard<'g> {
+    readers: &'g AtomicUsize,
+}
+
+impl<'g> ReadGuard<'g> {
+    fn new(readers: &'g [AtomicUsize; 2], epoch: &AtomicUsize) -> Self {
+        loop {
+            let current = epoch.load(Ordering::SeqCst);
+            let readers = &readers[current % 2];
+            readers.fetch_add(1, Ordering::SeqCst);
+
+            // enter again if a writer started waiting on the counter in the meantime
+            if epoch.load(Ordering::SeqCst) == current {
+                return Self { readers };
+            }
+            readers.fetch_sub(1, Ordering::SeqCst);
+        }
+    }
+}
+
+impl<'g> Drop for ReadGuard<'g> {
+    fn drop(&mut self) {
+        self.readers.fetch_sub(1, Ordering::SeqCst);
+    }
+}
@@ -0,0 +1,291 @@
+use crate::{addr::Addr, box_ref::BoxRef, field::Field, marker::*};
+use std::{
+    borrow::Borrow,
+    cmp, fmt,
+    fmt::{Debug, Display},
+    hash::{Hash, Hasher},
+    marker::PhantomData,
+    ops::{Deref, DerefMut},
+    ptr,
+};
+
+/// Content ordered field within an owner in [Box].
+pub type BoxFieldC<O, I = O> = BoxField<O, I, ByContent>;
+
+/// Pointer address ordered field within an owner in [Box].
+pub type BoxFieldA<O, I = O> = BoxField<O, I, ByAddress>;
+
+/// Field within an owner in [Box], which is located by a byte offset.
+///
+/// It stores the owner and a [Field] instead of a reference, so that it
+/// is two words in size and can be re-applied to another owner.
+pub struct BoxField<O, I, E>
+where
+    E: EqKind,
+{
+    pub(crate) _phantom: PhantomData<E>,
+    pub(crate) field: Field<O, I>,
+    pub(crate) owner: Box<O>,
+}
+
+unsafe impl<O, I, E> Send for BoxField<O, I, E>
+where
+    O: Send,
+    I: Send,
+    E: EqKind,
+{
+}
+
+unsafe impl<O, I, E> Sync for BoxField<O, I, E>
+where
+    O: Sync,
+    I: Sync,
+    E: EqKind,
+{
+}
+
+impl<O, E> BoxField<O, O, E>
+where
+    E: EqKind,
+{
+    /// Build from an owner.
+    pub fn new(owner: O) -> Self {
+        Box::new(owner).into()
+    }
+
+    /// Build from boxed data.
+    pub fn from_box(owner: Box<O>) -> Self {
+        owner.into()
+    }
+}
+
+impl<O, I, E> BoxField<O, I, E>
+where
+    E: EqKind,
+{
+    /// Build from boxed owner and a field within the owner.
+    pub fn from_field(owner: Box<O>, field: Field<O, I>) -> Self {
+        Self {
+            owner,
+            field,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Discard the field and return boxed owner.
+    pub fn into_box(from: BoxField<O, I, E>) -> Box<O> {
+        let Self { owner, .. } = from;
+        owner
+    }
+
+    /// Discard the field and return the owner.
+    pub fn into_owner(from: BoxField<O, I, E>) -> O {
+        let Self { owner, .. } = from;
+        *owner
+    }
+
+    /// Convert to [BoxRef].
+    pub fn into_box_ref<'a>(this: BoxField<O, I, E>) -> BoxRef<'a, O, I, E>
+    where
+        I: 'a,
+    {
+        let Self {
+            mut owner, field, ..
+        } = this;
+
+        unsafe {
+            // re-borrow to obtain 'a lifetime
+            let inner = &mut *field.project_mut(owner.as_mut());
+
+            BoxRef {
+                inner,
+                owner,
+                _phantom: PhantomData,
+            }
+        }
+    }
+
+    /// Get the field descriptor.
+    pub fn field(this: &BoxField<O, I, E>) -> Field<O, I> {
+        this.field
+    }
+
+    /// Get the reference to the owner.
+    pub fn owner(this: &BoxField<O, I, E>) -> &O {
+        &this.owner
+    }
+
+    /// Apply the same field to another `owner`.
+    pub fn with_owner(this: &BoxField<O, I, E>, owner: Box<O>) -> BoxField<O, I, E> {
+        Self::from_field(owner, this.field)
+    }
+
+    /// Clone the owner and apply the same field to the cloned owner.
+    pub fn deep_clone(this: &BoxField<O, I, E>) -> BoxField<O, I, E>
+    where
+        O: Clone,
+    {
+        Self::with_owner(this, this.owner.clone())
+    }
+
+    /// Project to the sub-field `field` of the current field.
+    pub fn map<T>(self, field: Field<I, T>) -> BoxField<O, T, E> {
+        let Self {
+            owner, field: orig, ..
+        } = self;
+        BoxField::from_field(owner, orig.then(field))
+    }
+
+    fn as_ptr(&self) -> *const I {
+        self.field.project(&*self.owner)
+    }
+}
+
+impl<O, I, E> Debug for BoxField<O, I, E>
+where
+    I: Debug,
+    E: EqKind,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        self.deref().fmt(f)
+    }
+}
+
+impl<O, I, E> Display for BoxField<O, I, E>
+where
+    I: Display,
+    E: EqKind,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        self.deref().fmt(f)
+    }
+}
+
+impl<O, I> PartialEq<Self> for BoxField<O, I, ByContent>
+where
+    I: PartialEq<I>,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.deref().eq(other.deref())
+    }
+}
+
+impl<O, I> Eq for BoxField<O, I, ByContent> where I: Eq {}
+
+impl<O, I> PartialOrd<Self> for BoxField<O, I, ByContent>
+where
+    I: PartialOrd<I>,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
+        self.deref().partial_cmp(other.deref())
+    }
+}
+
+impl<O, I> Ord for BoxField<O, I, ByContent>
+where
+    I: Ord,
+{
+    fn cmp(&self, other: &Self) -> cmp::Ordering {
+        self.deref().cmp(other.deref())
+    }
+}
+
+impl<O, I> Hash for BoxField<O, I, ByContent>
+where
+    I: Hash,
+{
+    fn hash<H>(&self, state: &mut H)
+    where
+        H: Hasher,
+    {
+        self.deref().hash(state);
+    }
+}
+
+impl<O, I> PartialEq<Self> for BoxField<O, I, ByAddress> {
+    fn eq(&self, other: &Self) -> bool {
+        ptr::eq(self.as_ptr(), other.as_ptr())
+    }
+}
+
+impl<O, I> Eq for BoxField<O, I, ByAddress> {}
+
+impl<O, I> PartialOrd<Self> for BoxField<O, I, ByAddress> {
+    fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<O, I> Ord for BoxField<O, I, ByAddress> {
+    fn cmp(&self, other: &Self) -> cmp::Ordering {
+        self.as_ptr().cmp(&other.as_ptr())
+    }
+}
+
+impl<O, I> Hash for BoxField<O, I, ByAddress> {
+    fn hash<H>(&self, state: &mut H)
+    where
+        H: Hasher,
+    {
+        ptr::hash(self.as_ptr(), state);
+    }
+}
+
+impl<O, I, E> AsRef<I> for BoxField<O, I, E>
+where
+    E: EqKind,
+{
+    fn as_ref(&self) -> &I {
+        self.deref()
+    }
+}
+
+impl<O, I, E> AsMut<I> for BoxField<O, I, E>
+where
+    E: EqKind,
+{
+    fn as_mut(&mut self) -> &mut I {
+        self.deref_mut()
+    }
+}
+
+impl<O, I> Borrow<I> for BoxField<O, I, ByContent> {
+    fn borrow(&self) -> &I {
+        self.deref()
+    }
+}
+
+impl<O, I> Borrow<Addr<I>> for BoxField<O, I, ByAddress> {
+    fn borrow(&self) -> &Addr<I> {
+        Addr::new(self.deref())
+    }
+}
+
+impl<O, I, E> Deref for BoxField<O, I, E>
+where
+    E: EqKind,
+{
+    type Target = I;
+
+    fn deref(&self) -> &Self::Target {
+        self.field.get(&self.owner)
+    }
+}
+
+impl<O, I, E> DerefMut for BoxField<O, I, E>
+where
+    E: EqKind,
+{
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.field.get_mut(&mut self.owner)
+    }
+}
+
+impl<O, E> From<Box<O>> for BoxField<O, O, E>
+where
+    E: EqKind,
+{
+    fn from(owner: Box<O>) -> Self {
+        Self::from_field(owner, Field::identity())
+    }
+}
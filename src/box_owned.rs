@@ -1,6 +1,9 @@
-use crate::{arc_owned::ArcOwned, arc_ref::ArcRef, box_ref::BoxRef, marker::*};
+use crate::{
+    addr::Addr, arc_owned::ArcOwned, arc_ref::ArcRef, box_ref::BoxRef, marker::*,
+    unique_arc::UniqueArc, utils::box_into_arc,
+};
 use std::{
-    any::Any,
+    any::{Any, TypeId},
     borrow::Borrow,
     cmp, fmt,
     fmt::{Debug, Display},
@@ -9,6 +12,8 @@ use std::{
     ops::{Deref, DerefMut},
     ptr,
 };
+#[cfg(feature = "unsize")]
+use std::{marker::Unsize, ops::CoerceUnsized};
 
 /// Content ordered owned data bundled with an owner in [Box].
 pub type BoxOwnedC<'a, O, I = &'a mut O> = BoxOwned<'a, O, I, ByContent>;
@@ -63,8 +68,19 @@ where
         owner
     }
 
-    /// Convert to [ArcOwned] without re-allocation.
-    pub fn into_arc_owned(from: BoxOwned<'a, O, I, E>) -> ArcOwned<'a, O, I, E> {
+    /// Convert to [ArcOwned].
+    ///
+    /// The owner is moved to a new allocation with the reference counts, while the data is
+    /// kept as is. Use [UniqueArc] owners and [freeze](BoxOwned::freeze) to share the owner
+    /// without moving it, or [into_arc_owned](BoxRef::into_arc_owned) on the [BoxRef] to
+    /// relocate a reference into the owner.
+    ///
+    /// # Safety
+    /// The data must not refer to the memory within the owner, which is freed by the
+    /// conversion. The references to the heap memory reachable from the owner stay valid.
+    /// The data is opaque to the conversion, so it cannot be relocated like the references
+    /// in [BoxRef] are.
+    pub unsafe fn into_arc_owned(from: BoxOwned<'a, O, I, E>) -> ArcOwned<'a, O, I, E> {
         let Self { owner, inner, .. } = from;
         ArcOwned {
             owner: owner.into(),
@@ -74,7 +90,7 @@ where
     }
 
     /// Reset data to the reference to owner.
-    pub fn into_owner_ref(this: BoxOwned<'a, O, I, E>) -> BoxOwned<'a, O, &mut O, E> {
+    pub fn into_owner_ref(this: BoxOwned<'a, O, I, E>) -> BoxOwned<'a, O, &'a mut O, E> {
         let Self {
             mut owner, inner, ..
         } = this;
@@ -97,6 +113,51 @@ where
         &this.owner
     }
 
+    /// Change the owner type by the coercion `f`, for example, to a trait object.
+    ///
+    /// Prefer the safe [coerce_owner!](crate::coerce_owner!) macro, or `coerce_owner_unsize()`
+    /// with the `unsize` feature. The function `f` is usually the cast `|owner| owner as _`
+    /// with the target type annotated, which performs the unsizing coercion to
+    /// `Box<dyn Trait>`.
+    ///
+    /// # Panic
+    /// The method panics if `f` returns an owner at a different address.
+    ///
+    /// # Safety
+    /// The function `f` must return the same owner with only its type changed. Replacing
+    /// the owner leaves the inner reference dangling, even if the new owner happens to be
+    /// allocated at the same address.
+    pub unsafe fn coerce_owner<U, F>(from: BoxOwned<'a, O, I, E>, f: F) -> BoxOwned<'a, U, I, E>
+    where
+        U: ?Sized,
+        F: FnOnce(Box<O>) -> Box<U>,
+    {
+        let Self { owner, inner, .. } = from;
+        let addr = &*owner as *const O as *const u8;
+        let owner = f(owner);
+        assert!(
+            ptr::eq(addr, &*owner as *const U as *const u8),
+            "the coercion must not replace the owner"
+        );
+
+        BoxOwned {
+            inner,
+            owner,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Change the owner type by the unsizing coercion, for example, to a trait object.
+    #[cfg(feature = "unsize")]
+    pub fn coerce_owner_unsize<U>(from: BoxOwned<'a, O, I, E>) -> BoxOwned<'a, U, I, E>
+    where
+        O: Unsize<U>,
+        U: ?Sized,
+    {
+        // the coercion keeps the same owner
+        unsafe { Self::coerce_owner(from, |owner| owner as _) }
+    }
+
     /// Applies function `f` to data.
     pub fn map<T, F>(self, f: F) -> BoxOwned<'a, O, T, E>
     where
@@ -192,6 +253,20 @@ where
     }
 }
 
+impl<'a, O, I, E> BoxOwned<'a, O, I, E>
+where
+    O: 'a + Clone,
+    E: EqKind,
+{
+    /// Clone the owner and build the data by function `f` on the cloned owner.
+    pub fn deep_clone_with<F>(this: &BoxOwned<'a, O, I, E>, f: F) -> BoxOwned<'a, O, I, E>
+    where
+        F: FnOnce(&'a mut O) -> I,
+    {
+        BoxOwned::from_box(this.owner.clone()).map(f)
+    }
+}
+
 impl<'a, O, I, E> BoxOwned<'a, O, &'a mut I, E>
 where
     O: ?Sized,
@@ -210,12 +285,17 @@ where
     }
 
     /// Convert to [ArcRef].
+    ///
+    /// The owner is moved to a new allocation with the reference counts, and the reference
+    /// is relocated if it refers to data within the owner.
     pub fn into_arc_ref(self) -> ArcRef<'a, O, I, E> {
         let Self { owner, inner, .. } = self;
+        let (owner, inner) = box_into_arc(owner, inner);
 
         ArcRef {
-            owner: owner.into(),
-            inner,
+            owner,
+            // re-borrow to obtain 'a lifetime
+            inner: unsafe { &*inner },
             _phantom: PhantomData,
         }
     }
@@ -228,12 +308,17 @@ where
     E: EqKind,
 {
     /// Convert to [ArcRef].
+    ///
+    /// The owner is moved to a new allocation with the reference counts, and the reference
+    /// is relocated if it refers to data within the owner.
     pub fn into_arc_ref(self) -> ArcRef<'a, O, I, E> {
         let Self { owner, inner, .. } = self;
+        let (owner, inner) = box_into_arc(owner, inner as *const I as *mut I);
 
         ArcRef {
-            owner: owner.into(),
-            inner,
+            owner,
+            // re-borrow to obtain 'a lifetime
+            inner: unsafe { &*inner },
             _phantom: PhantomData,
         }
     }
@@ -271,10 +356,48 @@ where
     }
 }
 
+impl<'a, O, I, E> BoxOwned<'a, UniqueArc<O>, I, E>
+where
+    O: ?Sized,
+    E: EqKind,
+{
+    /// Share the owner as [ArcOwned] without moving it.
+    ///
+    /// The owner is already allocated along with the reference counts, so the references
+    /// into the data in the owner stay valid. Use [freeze](BoxRef::freeze) on the
+    /// [BoxRef] for a checked conversion.
+    ///
+    /// # Safety
+    /// The data must not refer to the [UniqueArc] handle itself, which is freed by the
+    /// conversion. The references obtained by dereferencing the handle stay valid.
+    pub unsafe fn freeze(from: BoxOwned<'a, UniqueArc<O>, I, E>) -> ArcOwned<'a, O, I, E> {
+        let Self { owner, inner, .. } = from;
+
+        ArcOwned {
+            owner: UniqueArc::into_arc(*owner),
+            inner,
+            _phantom: PhantomData,
+        }
+    }
+}
+
 impl<'a, I, E> BoxOwned<'a, dyn Any + Send + 'static, I, E>
 where
     E: EqKind,
 {
+    /// Get the [TypeId] of the concrete owner type.
+    pub fn owner_type_id(this: &Self) -> TypeId {
+        (*this.owner).type_id()
+    }
+
+    /// Get the reference to the owner if the owner is of type `O`.
+    pub fn owner_downcast_ref<O>(this: &Self) -> Option<&O>
+    where
+        O: 'static,
+    {
+        this.owner.downcast_ref()
+    }
+
     /// Downcast the [Any] + [Send] trait object owner to concrete type.
     pub fn downcast_owner<O>(this: Self) -> Result<BoxOwned<'a, O, I, E>, Self>
     where
@@ -301,6 +424,19 @@ impl<'a, I, E> BoxOwned<'a, dyn Any + 'static, I, E>
 where
     E: EqKind,
 {
+    /// Get the [TypeId] of the concrete owner type.
+    pub fn owner_type_id(this: &Self) -> TypeId {
+        (*this.owner).type_id()
+    }
+
+    /// Get the reference to the owner if the owner is of type `O`.
+    pub fn owner_downcast_ref<O>(this: &Self) -> Option<&O>
+    where
+        O: 'static,
+    {
+        this.owner.downcast_ref()
+    }
+
     /// Downcast the [Any] trait object owner to concrete type.
     pub fn downcast_owner_local<O>(this: Self) -> Result<BoxOwned<'a, O, I, E>, Self>
     where
@@ -323,6 +459,34 @@ where
     }
 }
 
+impl<'a, O, E> BoxOwned<'a, O, &'a mut (dyn Any + 'static), E>
+where
+    O: ?Sized,
+    E: EqKind,
+{
+    /// Downcast the [Any] trait object data to concrete type.
+    pub fn downcast_inner<T>(this: Self) -> Result<BoxOwned<'a, O, &'a mut T, E>, Self>
+    where
+        T: 'static,
+    {
+        let Self { owner, inner, .. } = this;
+
+        if inner.is::<T>() {
+            Ok(BoxOwned {
+                owner,
+                inner: inner.downcast_mut().unwrap(),
+                _phantom: PhantomData,
+            })
+        } else {
+            Err(BoxOwned {
+                owner,
+                inner,
+                _phantom: PhantomData,
+            })
+        }
+    }
+}
+
 impl<'a, O, I, E> Debug for BoxOwned<'a, O, I, E>
 where
     O: ?Sized,
@@ -418,7 +582,7 @@ where
     I: ?Sized,
 {
     fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
-        (self.inner as *const I).partial_cmp(&(other.inner as *const I))
+        Some(self.cmp(other))
     }
 }
 
@@ -427,6 +591,7 @@ where
     O: ?Sized,
     I: ?Sized,
 {
+    #[allow(ambiguous_wide_pointer_comparisons)]
     fn cmp(&self, other: &Self) -> cmp::Ordering {
         (self.inner as *const I).cmp(&(other.inner as *const I))
     }
@@ -468,7 +633,7 @@ where
     I: ?Sized,
 {
     fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
-        (self.inner as *const I).partial_cmp(&(other.inner as *const I))
+        Some(self.cmp(other))
     }
 }
 
@@ -477,6 +642,7 @@ where
     O: ?Sized,
     I: ?Sized,
 {
+    #[allow(ambiguous_wide_pointer_comparisons)]
     fn cmp(&self, other: &Self) -> cmp::Ordering {
         (self.inner as *const I).cmp(&(other.inner as *const I))
     }
@@ -515,16 +681,35 @@ where
     }
 }
 
-impl<'a, O, I, E> Borrow<I> for BoxOwned<'a, O, I, E>
+impl<'a, O, I> Borrow<I> for BoxOwned<'a, O, I, ByContent>
 where
     O: ?Sized,
-    E: EqKind,
 {
     fn borrow(&self) -> &I {
         self.deref()
     }
 }
 
+impl<'a, O, I> Borrow<Addr<I>> for BoxOwned<'a, O, &'a I, ByAddress>
+where
+    O: ?Sized,
+    I: ?Sized,
+{
+    fn borrow(&self) -> &Addr<I> {
+        Addr::new(self.inner)
+    }
+}
+
+impl<'a, O, I> Borrow<Addr<I>> for BoxOwned<'a, O, &'a mut I, ByAddress>
+where
+    O: ?Sized,
+    I: ?Sized,
+{
+    fn borrow(&self) -> &Addr<I> {
+        Addr::new(self.inner)
+    }
+}
+
 impl<'a, O, I, E> Deref for BoxOwned<'a, O, I, E>
 where
     O: ?Sized,
@@ -565,3 +750,12 @@ where
         }
     }
 }
+
+#[cfg(feature = "unsize")]
+impl<'a, O, I, U, E> CoerceUnsized<BoxOwned<'a, O, U, E>> for BoxOwned<'a, O, I, E>
+where
+    O: ?Sized,
+    I: CoerceUnsized<U>,
+    E: EqKind,
+{
+}
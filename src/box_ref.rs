@@ -1,14 +1,25 @@
-use crate::{arc_owned::ArcOwned, arc_ref::ArcRef, box_owned::BoxOwned, marker::*};
+use crate::{
+    addr::Addr,
+    arc_owned::ArcOwned,
+    arc_ref::ArcRef,
+    box_owned::BoxOwned,
+    marker::*,
+    unique_arc::UniqueArc,
+    utils::{box_into_arc, offset_within, Caller},
+};
 use std::{
-    any::Any,
+    any::{Any, TypeId},
     borrow::Borrow,
     cmp, fmt,
     fmt::{Debug, Display},
     hash::{Hash, Hasher},
     marker::PhantomData,
+    mem,
     ops::{Deref, DerefMut},
     ptr,
 };
+#[cfg(feature = "unsize")]
+use std::{marker::Unsize, ops::CoerceUnsized};
 
 /// Content ordered reference to data within an owner in [Box].
 pub type BoxRefC<'a, O, I = O> = BoxRef<'a, O, I, ByContent>;
@@ -75,7 +86,7 @@ where
     }
 
     /// Convert to [BoxOwned].
-    pub fn into_box_owned(from: BoxRef<'a, O, I, E>) -> BoxOwned<'a, O, &mut I, E> {
+    pub fn into_box_owned(from: BoxRef<'a, O, I, E>) -> BoxOwned<'a, O, &'a mut I, E> {
         let Self { owner, inner, .. } = from;
         BoxOwned {
             owner,
@@ -84,22 +95,36 @@ where
         }
     }
 
-    /// Convert to [ArcOwned] without re-allocation.
+    /// Convert to [ArcOwned].
+    ///
+    /// The owner is moved to a new allocation with the reference counts, and the reference
+    /// is relocated if it refers to data within the owner. Use [UniqueArc] owners and
+    /// [freeze](BoxRef::freeze) to share the owner without moving it.
     pub fn into_arc_owned(from: BoxRef<'a, O, I, E>) -> ArcOwned<'a, O, &'a mut I, E> {
         let Self { owner, inner, .. } = from;
+        let (owner, inner) = box_into_arc(owner, inner);
+
         ArcOwned {
-            owner: owner.into(),
-            inner,
+            owner,
+            // re-borrow to obtain 'a lifetime
+            inner: unsafe { &mut *inner },
             _phantom: PhantomData,
         }
     }
 
-    /// Convert to [ArcRef] without re-allocation.
+    /// Convert to [ArcRef].
+    ///
+    /// The owner is moved to a new allocation with the reference counts, and the reference
+    /// is relocated if it refers to data within the owner. Use [UniqueArc] owners and
+    /// [freeze](BoxRef::freeze) to share the owner without moving it.
     pub fn into_arc_ref(from: BoxRef<'a, O, I, E>) -> ArcRef<'a, O, I, E> {
         let Self { owner, inner, .. } = from;
+        let (owner, inner) = box_into_arc(owner, inner);
+
         ArcRef {
-            owner: owner.into(),
-            inner,
+            owner,
+            // re-borrow to obtain 'a lifetime
+            inner: unsafe { &*inner },
             _phantom: PhantomData,
         }
     }
@@ -125,7 +150,60 @@ where
         &this.owner
     }
 
+    /// Change the owner type by the coercion `f`, for example, to a trait object.
+    ///
+    /// Prefer the safe [coerce_owner!](crate::coerce_owner!) macro, or `coerce_owner_unsize()`
+    /// with the `unsize` feature. The function `f` is usually the cast `|owner| owner as _`
+    /// with the target type annotated, which performs the unsizing coercion to
+    /// `Box<dyn Trait>`.
+    ///
+    /// # Panic
+    /// The method panics if `f` returns an owner at a different address.
+    ///
+    /// # Safety
+    /// The function `f` must return the same owner with only its type changed. Replacing
+    /// the owner leaves the inner reference dangling, even if the new owner happens to be
+    /// allocated at the same address.
+    pub unsafe fn coerce_owner<U, F>(from: BoxRef<'a, O, I, E>, f: F) -> BoxRef<'a, U, I, E>
+    where
+        U: ?Sized,
+        F: FnOnce(Box<O>) -> Box<U>,
+    {
+        let Self { owner, inner, .. } = from;
+        let addr = &*owner as *const O as *const u8;
+        let owner = f(owner);
+        assert!(
+            ptr::eq(addr, &*owner as *const U as *const u8),
+            "the coercion must not replace the owner"
+        );
+
+        BoxRef {
+            inner,
+            owner,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Change the owner type by the unsizing coercion, for example, to a trait object.
+    #[cfg(feature = "unsize")]
+    pub fn coerce_owner_unsize<U>(from: BoxRef<'a, O, I, E>) -> BoxRef<'a, U, I, E>
+    where
+        O: Unsize<U>,
+        U: ?Sized,
+    {
+        // the coercion keeps the same owner
+        unsafe { Self::coerce_owner(from, |owner| owner as _) }
+    }
+
+    /// Check if the referenced data lies within the memory of the owner.
+    ///
+    /// It returns `false` for the data on the heap memory pointed by the owner.
+    pub fn is_within_owner(this: &BoxRef<'a, O, I, E>) -> bool {
+        offset_within(&*this.owner, &*this.inner).is_some()
+    }
+
     /// Applies function `f` to inner reference.
+    #[cfg_attr(feature = "checked", track_caller)]
     pub fn map<T, F>(self, f: F) -> BoxRef<'a, O, T, E>
     where
         F: FnOnce(&'a mut I) -> &'a mut T,
@@ -135,12 +213,13 @@ where
 
         BoxRef {
             owner,
-            inner: f(inner),
+            inner: Caller::new().check(f(inner)),
             _phantom: PhantomData,
         }
     }
 
     /// Applies fallible function `f` to inner reference.
+    #[cfg_attr(feature = "checked", track_caller)]
     pub fn try_map<Ok, Err, F>(self, f: F) -> Result<BoxRef<'a, O, Ok, E>, Err>
     where
         F: FnOnce(&'a mut I) -> Result<&'a mut Ok, Err>,
@@ -150,12 +229,13 @@ where
 
         Ok(BoxRef {
             owner,
-            inner: f(inner)?,
+            inner: Caller::new().check(f(inner)?),
             _phantom: PhantomData,
         })
     }
 
     /// Applies function `f` that returns optional reference to inner reference.
+    #[cfg_attr(feature = "checked", track_caller)]
     pub fn filter_map<T, F>(self, f: F) -> Option<BoxRef<'a, O, T, E>>
     where
         F: FnOnce(&'a mut I) -> Option<&'a mut T>,
@@ -165,7 +245,7 @@ where
 
         Some(BoxRef {
             owner,
-            inner: f(inner)?,
+            inner: Caller::new().check(f(inner)?),
             _phantom: PhantomData,
         })
     }
@@ -210,11 +290,97 @@ where
     }
 }
 
+impl<'a, O, I, E> BoxRef<'a, O, I, E>
+where
+    O: 'a + Clone,
+    I: ?Sized,
+    E: EqKind,
+{
+    /// Clone the owner and project the inner reference by function `f` on the cloned owner.
+    pub fn deep_clone_with<F>(this: &BoxRef<'a, O, I, E>, f: F) -> BoxRef<'a, O, I, E>
+    where
+        F: FnOnce(&'a mut O) -> &'a mut I,
+    {
+        BoxRef::from_box(this.owner.clone()).map(f)
+    }
+}
+
+impl<'a, O, I, E> BoxRef<'a, O, I, E>
+where
+    O: Copy,
+    E: EqKind,
+{
+    /// Copy the owner and re-target the inner reference to the copied owner by relative offset.
+    ///
+    /// The owner is copied bit by bit, so the data stays at the same offset in the copy.
+    /// It returns `None` if the data does not lie within the memory of the owner. Use
+    /// [BoxRef::deep_clone_with()] for the owners that are not [Copy].
+    pub fn deep_clone(this: &BoxRef<'a, O, I, E>) -> Option<BoxRef<'a, O, I, E>> {
+        let offset = offset_within(&*this.owner, &*this.inner)?;
+        let mut owner = Box::new(*this.owner);
+
+        unsafe {
+            // re-borrow to obtain 'a lifetime
+            let inner = &mut *(owner.as_mut() as *mut O)
+                .cast::<u8>()
+                .add(offset)
+                .cast::<I>();
+
+            Some(BoxRef {
+                inner,
+                owner,
+                _phantom: PhantomData,
+            })
+        }
+    }
+}
+
+impl<'a, O, I, E> BoxRef<'a, UniqueArc<O>, I, E>
+where
+    O: ?Sized,
+    I: ?Sized,
+    E: EqKind,
+{
+    /// Share the owner as [ArcRef] without moving it.
+    ///
+    /// The owner is already allocated along with the reference counts, so the reference
+    /// stays valid without relocation.
+    ///
+    /// # Panic
+    /// The method panics if the reference refers to the [UniqueArc] handle rather than the data in it.
+    pub fn freeze(from: BoxRef<'a, UniqueArc<O>, I, E>) -> ArcRef<'a, O, I, E> {
+        let Self { owner, inner, .. } = from;
+        assert!(
+            mem::size_of_val(inner) == 0 || offset_within(&*owner, &*inner).is_none(),
+            "the reference must not refer to the UniqueArc handle"
+        );
+
+        ArcRef {
+            owner: UniqueArc::into_arc(*owner),
+            inner,
+            _phantom: PhantomData,
+        }
+    }
+}
+
 impl<'a, I, E> BoxRef<'a, dyn Any + Send + 'static, I, E>
 where
     I: ?Sized,
     E: EqKind,
 {
+    /// Get the [TypeId] of the concrete owner type.
+    pub fn owner_type_id(this: &Self) -> TypeId {
+        (*this.owner).type_id()
+    }
+
+    /// Get the reference to the owner if the owner is of type `O`.
+    pub fn owner_downcast_ref<O>(this: &Self) -> Option<&O>
+    where
+        O: 'static,
+    {
+        this.owner.downcast_ref()
+    }
+
     /// Downcast the [Any] + [Send] trait object owner to concrete type.
     pub fn downcast_owner<O>(this: Self) -> Result<BoxRef<'a, O, I, E>, Self>
     where
@@ -242,6 +408,19 @@ where
     I: ?Sized,
     E: EqKind,
 {
+    /// Get the [TypeId] of the concrete owner type.
+    pub fn owner_type_id(this: &Self) -> TypeId {
+        (*this.owner).type_id()
+    }
+
+    /// Get the reference to the owner if the owner is of type `O`.
+    pub fn owner_downcast_ref<O>(this: &Self) -> Option<&O>
+    where
+        O: 'static,
+    {
+        this.owner.downcast_ref()
+    }
+
     /// Downcast the [Any] trait object owner to concrete type.
     pub fn downcast_owner_local<O>(this: Self) -> Result<BoxRef<'a, O, I, E>, Self>
     where
@@ -264,6 +443,34 @@ where
     }
 }
 
+impl<'a, O, E> BoxRef<'a, O, dyn Any + 'static, E>
+where
+    O: ?Sized,
+    E: EqKind,
+{
+    /// Downcast the [Any] trait object data to concrete type.
+    pub fn downcast_inner<T>(this: Self) -> Result<BoxRef<'a, O, T, E>, Self>
+    where
+        T: 'static,
+    {
+        let Self { owner, inner, .. } = this;
+
+        if inner.is::<T>() {
+            Ok(BoxRef {
+                owner,
+                inner: inner.downcast_mut().unwrap(),
+                _phantom: PhantomData,
+            })
+        } else {
+            Err(BoxRef {
+                owner,
+                inner,
+                _phantom: PhantomData,
+            })
+        }
+    }
+}
+
 impl<'a, O, I, E> Debug for BoxRef<'a, O, I, E>
 where
     O: ?Sized,
@@ -365,7 +572,7 @@ where
     I: ?Sized,
 {
     fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
-        (self.inner as *const I).partial_cmp(&(other.inner as *const I))
+        Some(self.cmp(other))
     }
 }
 
@@ -374,6 +581,7 @@ where
     O: ?Sized,
     I: ?Sized,
 {
+    #[allow(ambiguous_wide_pointer_comparisons)]
     fn cmp(&self, other: &Self) -> cmp::Ordering {
         (self.inner as *const I).cmp(&(other.inner as *const I))
     }
@@ -414,17 +622,26 @@ where
     }
 }
 
-impl<'a, O, I, E> Borrow<I> for BoxRef<'a, O, I, E>
+impl<'a, O, I> Borrow<I> for BoxRef<'a, O, I, ByContent>
 where
     O: ?Sized,
     I: ?Sized,
-    E: EqKind,
 {
     fn borrow(&self) -> &I {
         self.deref()
     }
 }
 
+impl<'a, O, I> Borrow<Addr<I>> for BoxRef<'a, O, I, ByAddress>
+where
+    O: ?Sized,
+    I: ?Sized,
+{
+    fn borrow(&self) -> &Addr<I> {
+        Addr::new(self.inner)
+    }
+}
+
 impl<'a, O, I, E> Deref for BoxRef<'a, O, I, E>
 where
     O: ?Sized,
@@ -467,3 +684,13 @@ where
         }
     }
 }
+
+#[cfg(feature = "unsize")]
+impl<'a, O, I, U, E> CoerceUnsized<BoxRef<'a, O, U, E>> for BoxRef<'a, O, I, E>
+where
+    O: ?Sized,
+    I: ?Sized + Unsize<U>,
+    U: ?Sized,
+    E: EqKind,
+{
+}
@@ -0,0 +1,202 @@
+use crate::{arc_ref::ArcRef, box_ref::BoxRef, marker::*};
+use std::io::{self, BufRead, Read, Write};
+
+impl<'a, E> ArcRef<'a, Vec<u8>, [u8], E>
+where
+    E: EqKind,
+{
+    /// Read all bytes from `reader` into a new owner, and refer to the bytes.
+    pub fn read_from<R>(mut reader: R) -> io::Result<Self>
+    where
+        R: Read,
+    {
+        let mut buf = vec![];
+        reader.read_to_end(&mut buf)?;
+        Ok(ArcRef::new(buf).map(|buf| buf.as_slice()))
+    }
+}
+
+impl<'a, E> BoxRef<'a, Vec<u8>, [u8], E>
+where
+    E: EqKind,
+{
+    /// Read all bytes from `reader` into a new owner, and refer to the bytes.
+    pub fn read_from<R>(mut reader: R) -> io::Result<Self>
+    where
+        R: Read,
+    {
+        let mut buf = vec![];
+        reader.read_to_end(&mut buf)?;
+        Ok(BoxRef::new(buf).map(|buf| buf.as_mut_slice()))
+    }
+}
+
+/// Content ordered cursor reading the bytes referred by [ArcRef].
+pub type ArcRefCursorC<'a, O> = ArcRefCursor<'a, O, ByContent>;
+
+/// Pointer address ordered cursor reading the bytes referred by [ArcRef].
+pub type ArcRefCursorA<'a, O> = ArcRefCursor<'a, O, ByAddress>;
+
+/// Content ordered cursor writing the bytes referred by [BoxRef].
+pub type BoxRefCursorC<'a, O> = BoxRefCursor<'a, O, ByContent>;
+
+/// Pointer address ordered cursor writing the bytes referred by [BoxRef].
+pub type BoxRefCursorA<'a, O> = BoxRefCursor<'a, O, ByAddress>;
+
+/// Cursor on the bytes referred by [ArcRef], which implements [Read] and [BufRead].
+///
+/// It works like [io::Cursor] and leaves the reference untouched.
+pub struct ArcRefCursor<'a, O, E>
+where
+    O: ?Sized,
+    E: EqKind,
+{
+    pub(crate) bytes: ArcRef<'a, O, [u8], E>,
+    pub(crate) pos: usize,
+}
+
+impl<'a, O, E> ArcRefCursor<'a, O, E>
+where
+    O: ?Sized,
+    E: EqKind,
+{
+    /// Build a cursor at the start of `bytes`.
+    pub fn new(bytes: ArcRef<'a, O, [u8], E>) -> Self {
+        Self { bytes, pos: 0 }
+    }
+
+    /// Get the reference to the bytes.
+    pub fn get_ref(&self) -> &ArcRef<'a, O, [u8], E> {
+        &self.bytes
+    }
+
+    /// Discard the cursor and return the reference to the bytes.
+    pub fn into_inner(self) -> ArcRef<'a, O, [u8], E> {
+        self.bytes
+    }
+
+    /// Get the position of the cursor.
+    pub fn position(&self) -> usize {
+        self.pos
+    }
+
+    /// Move the cursor to `pos`, which may go past the end.
+    pub fn set_position(&mut self, pos: usize) {
+        self.pos = pos;
+    }
+
+    /// Refer to the bytes after the cursor with the same owner.
+    pub fn remaining(&self) -> ArcRef<'a, O, [u8], E> {
+        ArcRef::slice(&self.bytes, self.start()..)
+    }
+
+    fn start(&self) -> usize {
+        self.pos.min(self.bytes.len())
+    }
+}
+
+impl<'a, O, E> Read for ArcRefCursor<'a, O, E>
+where
+    O: ?Sized,
+    E: EqKind,
+{
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let len = self.fill_buf()?.read(buf)?;
+        self.pos += len;
+        Ok(len)
+    }
+}
+
+impl<'a, O, E> BufRead for ArcRefCursor<'a, O, E>
+where
+    O: ?Sized,
+    E: EqKind,
+{
+    fn fill_buf(&mut self) -> io::Result<&[u8]> {
+        Ok(&self.bytes.inner[self.start()..])
+    }
+
+    fn consume(&mut self, amt: usize) {
+        self.pos += amt;
+    }
+}
+
+impl<'a, O, E> From<ArcRef<'a, O, [u8], E>> for ArcRefCursor<'a, O, E>
+where
+    O: ?Sized,
+    E: EqKind,
+{
+    fn from(bytes: ArcRef<'a, O, [u8], E>) -> Self {
+        Self::new(bytes)
+    }
+}
+
+/// Cursor on the bytes referred by [BoxRef], which implements [Write].
+///
+/// It works like [io::Cursor] on `&mut [u8]` and leaves the reference untouched.
+pub struct BoxRefCursor<'a, O, E>
+where
+    O: ?Sized,
+    E: EqKind,
+{
+    pub(crate) bytes: BoxRef<'a, O, [u8], E>,
+    pub(crate) pos: usize,
+}
+
+impl<'a, O, E> BoxRefCursor<'a, O, E>
+where
+    O: ?Sized,
+    E: EqKind,
+{
+    /// Build a cursor at the start of `bytes`.
+    pub fn new(bytes: BoxRef<'a, O, [u8], E>) -> Self {
+        Self { bytes, pos: 0 }
+    }
+
+    /// Get the reference to the bytes.
+    pub fn get_ref(&self) -> &BoxRef<'a, O, [u8], E> {
+        &self.bytes
+    }
+
+    /// Discard the cursor and return the reference to the bytes.
+    pub fn into_inner(self) -> BoxRef<'a, O, [u8], E> {
+        self.bytes
+    }
+
+    /// Get the position of the cursor.
+    pub fn position(&self) -> usize {
+        self.pos
+    }
+
+    /// Move the cursor to `pos`, which may go past the end.
+    pub fn set_position(&mut self, pos: usize) {
+        self.pos = pos;
+    }
+}
+
+impl<'a, O, E> Write for BoxRefCursor<'a, O, E>
+where
+    O: ?Sized,
+    E: EqKind,
+{
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let start = self.pos.min(self.bytes.len());
+        let len = (&mut self.bytes.inner[start..]).write(buf)?;
+        self.pos += len;
+        Ok(len)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+impl<'a, O, E> From<BoxRef<'a, O, [u8], E>> for BoxRefCursor<'a, O, E>
+where
+    O: ?Sized,
+    E: EqKind,
+{
+    fn from(bytes: BoxRef<'a, O, [u8], E>) -> Self {
+        Self::new(bytes)
+    }
+}
@@ -0,0 +1,120 @@
+use crate::{
+    arc_owned::ArcOwned, arc_ref::ArcRef, box_owned::BoxOwned, box_ref::BoxRef, marker::*,
+};
+use std::sync::Arc;
+
+/// Change the owner type of a smart reference by the unsizing coercion, for example, to a
+/// trait object.
+///
+/// It accepts [ArcRef], [BoxRef], [ArcOwned] and [BoxOwned], and the target owner type.
+/// The coercion is performed by the cast within the macro, so that the owner is never
+/// replaced.
+///
+/// ```
+/// # use ownref::{coerce_owner, ArcRefC};
+/// # use std::fmt::Debug;
+/// let text = ArcRefC::new(String::from("text")).map(|text| text.as_str());
+/// let text: ArcRefC<dyn Debug + Send + Sync, str> = coerce_owner!(text, dyn Debug + Send + Sync);
+/// assert_eq!(&*text, "text");
+/// ```
+#[macro_export]
+macro_rules! coerce_owner {
+    ($value:expr, $target:ty $(,)?) => {
+        match $value {
+            value => unsafe {
+                $crate::CoerceOwner::<$target>::coerce_owner(value, |owner| owner as _)
+            },
+        }
+    };
+}
+
+/// The smart references of which the owner type can be changed, used by [coerce_owner!].
+#[doc(hidden)]
+pub trait CoerceOwner<U>
+where
+    U: ?Sized,
+{
+    type Owner;
+    type Target;
+    type Output;
+
+    /// # Safety
+    /// The function `f` must return the same owner with only its type changed.
+    unsafe fn coerce_owner<F>(self, f: F) -> Self::Output
+    where
+        F: FnOnce(Self::Owner) -> Self::Target;
+}
+
+impl<'a, O, I, U, E> CoerceOwner<U> for ArcRef<'a, O, I, E>
+where
+    O: ?Sized,
+    I: ?Sized,
+    U: ?Sized,
+    E: EqKind,
+{
+    type Owner = Arc<O>;
+    type Target = Arc<U>;
+    type Output = ArcRef<'a, U, I, E>;
+
+    unsafe fn coerce_owner<F>(self, f: F) -> Self::Output
+    where
+        F: FnOnce(Self::Owner) -> Self::Target,
+    {
+        ArcRef::coerce_owner(self, f)
+    }
+}
+
+impl<'a, O, I, U, E> CoerceOwner<U> for BoxRef<'a, O, I, E>
+where
+    O: ?Sized,
+    I: ?Sized,
+    U: ?Sized,
+    E: EqKind,
+{
+    type Owner = Box<O>;
+    type Target = Box<U>;
+    type Output = BoxRef<'a, U, I, E>;
+
+    unsafe fn coerce_owner<F>(self, f: F) -> Self::Output
+    where
+        F: FnOnce(Self::Owner) -> Self::Target,
+    {
+        BoxRef::coerce_owner(self, f)
+    }
+}
+
+impl<'a, O, I, U, E> CoerceOwner<U> for ArcOwned<'a, O, I, E>
+where
+    O: ?Sized,
+    U: ?Sized,
+    E: EqKind,
+{
+    type Owner = Arc<O>;
+    type Target = Arc<U>;
+    type Output = ArcOwned<'a, U, I, E>;
+
+    unsafe fn coerce_owner<F>(self, f: F) -> Self::Output
+    where
+        F: FnOnce(Self::Owner) -> Self::Target,
+    {
+        ArcOwned::coerce_owner(self, f)
+    }
+}
+
+impl<'a, O, I, U, E> CoerceOwner<U> for BoxOwned<'a, O, I, E>
+where
+    O: ?Sized,
+    U: ?Sized,
+    E: EqKind,
+{
+    type Owner = Box<O>;
+    type Target = Box<U>;
+    type Output = BoxOwned<'a, U, I, E>;
+
+    unsafe fn coerce_owner<F>(self, f: F) -> Self::Output
+    where
+        F: FnOnce(Self::Owner) -> Self::Target,
+    {
+        BoxOwned::coerce_owner(self, f)
+    }
+}
@@ -0,0 +1,148 @@
+use std::{
+    fmt,
+    fmt::Debug,
+    hash::{Hash, Hasher},
+    marker::PhantomData,
+};
+
+/// Build a [Field](crate::Field) from a field path of an owner type.
+///
+/// The offset is computed by [offset_of](std::mem::offset_of), and the
+/// field type is inferred from the path.
+///
+/// ```
+/// use ownref::{field, Field};
+///
+/// struct Header {
+///     id: u32,
+///     name: &'static str,
+/// }
+///
+/// struct Owner {
+///     header: Header,
+///     size: usize,
+/// }
+///
+/// let name: Field<Owner, &'static str> = field!(Owner, header.name);
+///
+/// // equivalent to the composition of two fields
+/// let header: Field<Owner, Header> = field!(Owner, header);
+/// assert_eq!(name, header.then(field!(Header, name)));
+/// ```
+#[macro_export]
+macro_rules! field {
+    ($owner:ty, $($path:tt)+) => {
+        unsafe {
+            $crate::Field::<$owner, _>::from_offset_of(
+                ::std::mem::offset_of!($owner, $($path)+),
+                |owner: &$owner| &owner.$($path)+,
+            )
+        }
+    };
+}
+
+/// Byte offset of a field of type `I` within the owner type `O`.
+///
+/// It is a [Copy]-able descriptor of a projection, which can be
+/// composed by [Field::then()] and applied to any owner of type `O`.
+/// It is usually built by the [field!](crate::field!) macro.
+pub struct Field<O, I> {
+    _phantom: PhantomData<fn(&O) -> &I>,
+    offset: usize,
+}
+
+impl<O> Field<O, O> {
+    /// Build the field referring to the owner itself.
+    pub const fn identity() -> Self {
+        unsafe { Self::from_offset(0) }
+    }
+}
+
+impl<O, I> Field<O, I> {
+    /// Build from the byte offset of a field.
+    ///
+    /// # Safety
+    /// The `offset` must be the byte offset of a field of type `I` within `O`.
+    pub const unsafe fn from_offset(offset: usize) -> Self {
+        Self {
+            offset,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Build from the byte offset of a field, and infer the field type from
+    /// the unused function `f`. It is used by the [field!](crate::field!) macro.
+    ///
+    /// # Safety
+    /// The `offset` must be the byte offset of the field that `f` refers to.
+    #[doc(hidden)]
+    pub unsafe fn from_offset_of<F>(offset: usize, _f: F) -> Self
+    where
+        F: FnOnce(&O) -> &I,
+    {
+        Self::from_offset(offset)
+    }
+
+    /// Get the byte offset of the field.
+    pub const fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Compose with a field `other` of the field type.
+    pub const fn then<T>(self, other: Field<I, T>) -> Field<O, T> {
+        unsafe { Field::from_offset(self.offset + other.offset) }
+    }
+
+    /// Get the reference to the field within the `owner`.
+    pub fn get<'b>(&self, owner: &'b O) -> &'b I {
+        unsafe { &*self.project(owner) }
+    }
+
+    /// Get the mutable reference to the field within the `owner`.
+    pub fn get_mut<'b>(&self, owner: &'b mut O) -> &'b mut I {
+        unsafe { &mut *self.project_mut(owner) }
+    }
+
+    /// Compute the field pointer from the owner pointer.
+    pub(crate) fn project(&self, owner: *const O) -> *const I {
+        owner.cast::<u8>().wrapping_add(self.offset).cast()
+    }
+
+    /// Compute the mutable field pointer from the owner pointer.
+    pub(crate) fn project_mut(&self, owner: *mut O) -> *mut I {
+        owner.cast::<u8>().wrapping_add(self.offset).cast()
+    }
+}
+
+impl<O, I> Clone for Field<O, I> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<O, I> Copy for Field<O, I> {}
+
+impl<O, I> Debug for Field<O, I> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        f.debug_struct("Field")
+            .field("offset", &self.offset)
+            .finish()
+    }
+}
+
+impl<O, I> PartialEq<Self> for Field<O, I> {
+    fn eq(&self, other: &Self) -> bool {
+        self.offset == other.offset
+    }
+}
+
+impl<O, I> Eq for Field<O, I> {}
+
+impl<O, I> Hash for Field<O, I> {
+    fn hash<H>(&self, state: &mut H)
+    where
+        H: Hasher,
+    {
+        self.offset.hash(state);
+    }
+}
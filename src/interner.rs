@@ -0,0 +1,202 @@
+//! String interning with shared chunk owners.
+//!
+//! [Interner] stores strings in large [Chunk]s and returns `ArcRef<'a, Chunk, str, E>`
+//! handles. Each distinct string is stored once, so the handles to equal strings from
+//! the same interner point to the same address, and `ArcRefA` compares them in constant time.
+
+use crate::{arc_ref::ArcRef, marker::*};
+use std::{
+    cell::UnsafeCell,
+    collections::HashSet,
+    fmt,
+    fmt::Debug,
+    marker::PhantomData,
+    sync::{Arc, Mutex, MutexGuard},
+};
+
+const DEFAULT_CHUNK_SIZE: usize = 4096;
+
+/// Shared owner of interned strings.
+///
+/// The strings are appended to a buffer that is never re-allocated, so the interned
+/// strings stay at the same address.
+pub struct Chunk {
+    text: UnsafeCell<String>,
+}
+
+// The buffer is only appended by the interner with the lock held, and the handles only
+// read the strings before the end of the buffer.
+unsafe impl Send for Chunk {}
+unsafe impl Sync for Chunk {}
+
+impl Chunk {
+    fn with_capacity(capacity: usize) -> Self {
+        Self {
+            text: UnsafeCell::new(String::with_capacity(capacity)),
+        }
+    }
+
+    /// Get the remaining space in the buffer.
+    ///
+    /// # Safety
+    /// The caller must hold the interner lock.
+    unsafe fn remaining(&self) -> usize {
+        let text = &*self.text.get();
+        text.capacity() - text.len()
+    }
+
+    /// Append `text` to the buffer and return the appended string.
+    ///
+    /// # Safety
+    /// The caller must hold the interner lock, and the text must fit in the remaining space.
+    unsafe fn push(&self, text: &str) -> *const str {
+        let buf = &mut *self.text.get();
+        let start = buf.len();
+        debug_assert!(text.len() <= buf.capacity() - start);
+
+        buf.push_str(text);
+        &buf[start..]
+    }
+}
+
+impl Debug for Chunk {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        f.debug_struct("Chunk").finish_non_exhaustive()
+    }
+}
+
+/// Thread-safe string interner.
+///
+/// The handles to equal strings from the same interner share the same address, while
+/// the handles from different interners never do.
+pub struct Interner {
+    state: Mutex<State>,
+}
+
+struct State {
+    chunk_size: usize,
+    chunk: Arc<Chunk>,
+    symbols: HashSet<ArcRef<'static, Chunk, str, ByContent>>,
+}
+
+impl Interner {
+    /// Create an empty interner.
+    pub fn new() -> Self {
+        Self::with_chunk_size(DEFAULT_CHUNK_SIZE)
+    }
+
+    /// Create an empty interner allocating chunks of `chunk_size` bytes.
+    ///
+    /// Strings longer than `chunk_size` are stored in dedicated chunks.
+    pub fn with_chunk_size(chunk_size: usize) -> Self {
+        Self {
+            state: Mutex::new(State {
+                chunk_size,
+                chunk: Arc::new(Chunk::with_capacity(chunk_size)),
+                symbols: HashSet::new(),
+            }),
+        }
+    }
+
+    /// Intern `text` and return the handle to the interned string.
+    pub fn intern<'a, E>(&self, text: &str) -> ArcRef<'a, Chunk, str, E>
+    where
+        E: EqKind,
+    {
+        let mut state = self.lock();
+
+        if let Some(symbol) = state.symbols.get(text) {
+            return convert(symbol);
+        }
+
+        let symbol = state.insert(text);
+        let output = convert(&symbol);
+        state.symbols.insert(symbol);
+        output
+    }
+
+    /// Intern the string in an existing reference.
+    ///
+    /// It returns the handle to the interned string without copying if the string is
+    /// already interned.
+    pub fn intern_ref<'a, O, E>(&self, text: ArcRef<'a, O, str, E>) -> ArcRef<'a, Chunk, str, E>
+    where
+        O: ?Sized,
+        E: EqKind,
+    {
+        self.intern(&text)
+    }
+
+    /// Get the handle to `text` if it is interned.
+    pub fn get<'a, E>(&self, text: &str) -> Option<ArcRef<'a, Chunk, str, E>>
+    where
+        E: EqKind,
+    {
+        self.lock().symbols.get(text).map(convert)
+    }
+
+    /// Get the number of interned strings.
+    pub fn len(&self) -> usize {
+        self.lock().symbols.len()
+    }
+
+    /// Check if no string is interned.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn lock(&self) -> MutexGuard<'_, State> {
+        // the state is always consistent, so a poisoned lock is fine to use
+        self.state.lock().unwrap_or_else(|err| err.into_inner())
+    }
+}
+
+impl State {
+    /// Copy `text` into a chunk.
+    fn insert(&mut self, text: &str) -> ArcRef<'static, Chunk, str, ByContent> {
+        let len = text.len();
+
+        let owner = if len > self.chunk_size {
+            Arc::new(Chunk::with_capacity(len))
+        } else {
+            if unsafe { self.chunk.remaining() } < len {
+                self.chunk = Arc::new(Chunk::with_capacity(self.chunk_size));
+            }
+            self.chunk.clone()
+        };
+        let inner = unsafe { owner.push(text) };
+
+        ArcRef {
+            // re-borrow to obtain 'static lifetime
+            inner: unsafe { &*inner },
+            owner,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl Default for Interner {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Debug for Interner {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        f.debug_struct("Interner")
+            .field("len", &self.len())
+            .finish()
+    }
+}
+
+/// Copy the handle with another comparison kind.
+fn convert<'a, E>(symbol: &ArcRef<'static, Chunk, str, ByContent>) -> ArcRef<'a, Chunk, str, E>
+where
+    E: EqKind,
+{
+    ArcRef {
+        owner: symbol.owner.clone(),
+        inner: symbol.inner,
+        _phantom: PhantomData,
+    }
+}
@@ -0,0 +1,306 @@
+use crate::{arc_owned::ArcOwned, arc_ref::ArcRef, box_ref::BoxRef, marker::*};
+use std::{
+    borrow::Cow,
+    collections::{BTreeMap, HashMap},
+    fmt,
+    fmt::{Debug, Display},
+    hash::{BuildHasher, Hash},
+    sync::Arc,
+};
+
+type Getter<O, I> = Arc<dyn Fn(&O) -> Option<&I> + Send + Sync>;
+type GetterMut<O, I> = Arc<dyn Fn(&mut O) -> Option<&mut I> + Send + Sync>;
+
+/// A step of the path described by a [Lens].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Segment {
+    /// A named field, printed as `.name`.
+    Field(Cow<'static, str>),
+    /// An index into a sequence, printed as `[3]`.
+    Index(usize),
+    /// A key into a map, printed as `["key"]`. It stores the [Debug] representation of the key.
+    Key(String),
+    /// An enum variant, printed as `::Variant`.
+    Variant(Cow<'static, str>),
+}
+
+impl Display for Segment {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        match self {
+            Segment::Field(name) => write!(f, ".{}", name),
+            Segment::Index(index) => write!(f, "[{}]", index),
+            Segment::Key(key) => write!(f, "[{}]", key),
+            Segment::Variant(name) => write!(f, "::{}", name),
+        }
+    }
+}
+
+/// Map-like collections that can be looked up by a key of type `K`.
+pub trait Keyed<K> {
+    type Value;
+
+    /// Get the reference to the value of `key`.
+    fn get_by_key(&self, key: &K) -> Option<&Self::Value>;
+
+    /// Get the mutable reference to the value of `key`.
+    fn get_by_key_mut(&mut self, key: &K) -> Option<&mut Self::Value>;
+}
+
+impl<K, V, S> Keyed<K> for HashMap<K, V, S>
+where
+    K: Eq + Hash,
+    S: BuildHasher,
+{
+    type Value = V;
+
+    fn get_by_key(&self, key: &K) -> Option<&V> {
+        self.get(key)
+    }
+
+    fn get_by_key_mut(&mut self, key: &K) -> Option<&mut V> {
+        self.get_mut(key)
+    }
+}
+
+impl<K, V> Keyed<K> for BTreeMap<K, V>
+where
+    K: Ord,
+{
+    type Value = V;
+
+    fn get_by_key(&self, key: &K) -> Option<&V> {
+        self.get(key)
+    }
+
+    fn get_by_key_mut(&mut self, key: &K) -> Option<&mut V> {
+        self.get_mut(key)
+    }
+}
+
+/// Reusable projection path from the type `O` to the type `I`.
+///
+/// The path is a sequence of [Segment]s, which is built from fields, indexes,
+/// map keys and enum variants, and is composed by [Lens::then()]. A lens can be
+/// stored and applied to any number of owners.
+///
+/// ```
+/// use ownref::{ArcRefC, Lens};
+///
+/// struct User {
+///     name: String,
+/// }
+///
+/// struct Model {
+///     users: Vec<User>,
+/// }
+///
+/// let lens: Lens<Model, String> = Lens::field("users", |m: &Model| &m.users, |m| &mut m.users)
+///     .then(Lens::index(1))
+///     .then(Lens::field("name", |u: &User| &u.name, |u| &mut u.name));
+/// assert_eq!(format!("{:?}", lens), ".users[1].name");
+///
+/// let model = ArcRefC::new(Model {
+///     users: vec![
+///         User {
+///             name: "alice".into(),
+///         },
+///         User { name: "bob".into() },
+///     ],
+/// });
+/// let name: ArcRefC<Model, String> = lens.apply_arc_ref(model).unwrap();
+/// assert_eq!(*name, "bob");
+/// ```
+pub struct Lens<O, I>
+where
+    I: ?Sized,
+{
+    path: Vec<Segment>,
+    get: Getter<O, I>,
+    get_mut: GetterMut<O, I>,
+}
+
+impl<O> Lens<O, O>
+where
+    O: 'static,
+{
+    /// Build the lens that refers to the value itself.
+    pub fn identity() -> Self {
+        Self {
+            path: vec![],
+            get: Arc::new(|owner| Some(owner)),
+            get_mut: Arc::new(|owner| Some(owner)),
+        }
+    }
+}
+
+impl<O, I> Lens<O, I>
+where
+    O: 'static,
+    I: 'static + ?Sized,
+{
+    /// Build a lens of one path segment from a getter and a mutable getter.
+    pub fn new<G, M>(segment: Segment, get: G, get_mut: M) -> Self
+    where
+        G: 'static + Fn(&O) -> Option<&I> + Send + Sync,
+        M: 'static + Fn(&mut O) -> Option<&mut I> + Send + Sync,
+    {
+        Self {
+            path: vec![segment],
+            get: Arc::new(get),
+            get_mut: Arc::new(get_mut),
+        }
+    }
+
+    /// Build the lens to a named field.
+    pub fn field<G, M>(name: impl Into<Cow<'static, str>>, get: G, get_mut: M) -> Self
+    where
+        G: 'static + Fn(&O) -> &I + Send + Sync,
+        M: 'static + Fn(&mut O) -> &mut I + Send + Sync,
+    {
+        Self::new(
+            Segment::Field(name.into()),
+            move |owner| Some(get(owner)),
+            move |owner| Some(get_mut(owner)),
+        )
+    }
+
+    /// Build the lens to an enum variant.
+    pub fn variant<G, M>(name: impl Into<Cow<'static, str>>, get: G, get_mut: M) -> Self
+    where
+        G: 'static + Fn(&O) -> Option<&I> + Send + Sync,
+        M: 'static + Fn(&mut O) -> Option<&mut I> + Send + Sync,
+    {
+        Self::new(Segment::Variant(name.into()), get, get_mut)
+    }
+
+    /// Get the path of the lens.
+    pub fn path(&self) -> &[Segment] {
+        &self.path
+    }
+
+    /// Get the reference to the target within `owner`.
+    pub fn get<'b>(&self, owner: &'b O) -> Option<&'b I> {
+        (self.get)(owner)
+    }
+
+    /// Get the mutable reference to the target within `owner`.
+    pub fn get_mut<'b>(&self, owner: &'b mut O) -> Option<&'b mut I> {
+        (self.get_mut)(owner)
+    }
+
+    /// Compose with the lens `other` that starts from the target of this lens.
+    pub fn then<T>(&self, other: Lens<I, T>) -> Lens<O, T>
+    where
+        I: Sized,
+        T: 'static + ?Sized,
+    {
+        let Lens {
+            path: other_path,
+            get: other_get,
+            get_mut: other_get_mut,
+        } = other;
+        let get = self.get.clone();
+        let get_mut = self.get_mut.clone();
+
+        Lens {
+            path: self.path.iter().cloned().chain(other_path).collect(),
+            get: Arc::new(move |owner| other_get(get(owner)?)),
+            get_mut: Arc::new(move |owner| other_get_mut(get_mut(owner)?)),
+        }
+    }
+
+    /// Apply the lens on the data of an [ArcRef].
+    pub fn apply_arc_ref<'a, R, E>(&self, from: ArcRef<'a, R, O, E>) -> Option<ArcRef<'a, R, I, E>>
+    where
+        R: ?Sized,
+        E: EqKind,
+    {
+        from.filter_map(|owner| self.get(owner))
+    }
+
+    /// Apply the lens on the data of a [BoxRef].
+    pub fn apply_box_ref<'a, R, E>(&self, from: BoxRef<'a, R, O, E>) -> Option<BoxRef<'a, R, I, E>>
+    where
+        R: ?Sized,
+        E: EqKind,
+    {
+        from.filter_map(|owner| self.get_mut(owner))
+    }
+
+    /// Apply the lens on the data of an [ArcOwned].
+    pub fn apply_arc_owned<'a, R, E>(
+        &self,
+        from: ArcOwned<'a, R, &'a O, E>,
+    ) -> Option<ArcOwned<'a, R, &'a I, E>>
+    where
+        R: ?Sized,
+        E: EqKind,
+    {
+        from.filter_map(|owner| self.get(owner))
+    }
+}
+
+impl<O, I> Lens<O, I>
+where
+    O: 'static + AsRef<[I]> + AsMut<[I]>,
+    I: 'static,
+{
+    /// Build the lens to an element of a sequence.
+    pub fn index(index: usize) -> Self {
+        Self::new(
+            Segment::Index(index),
+            move |owner: &O| owner.as_ref().get(index),
+            move |owner: &mut O| owner.as_mut().get_mut(index),
+        )
+    }
+}
+
+impl<O, I> Lens<O, I>
+where
+    O: 'static,
+    I: 'static,
+{
+    /// Build the lens to a value of a map.
+    pub fn key<K>(key: K) -> Self
+    where
+        O: Keyed<K, Value = I>,
+        K: 'static + Debug + Send + Sync,
+    {
+        let key = Arc::new(key);
+        let key_mut = key.clone();
+
+        Self::new(
+            Segment::Key(format!("{:?}", key)),
+            move |owner: &O| owner.get_by_key(&key),
+            move |owner: &mut O| owner.get_by_key_mut(&key_mut),
+        )
+    }
+}
+
+impl<O, I> Clone for Lens<O, I>
+where
+    I: ?Sized,
+{
+    fn clone(&self) -> Self {
+        Self {
+            path: self.path.clone(),
+            get: self.get.clone(),
+            get_mut: self.get_mut.clone(),
+        }
+    }
+}
+
+impl<O, I> Debug for Lens<O, I>
+where
+    I: ?Sized,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        if self.path.is_empty() {
+            return write!(f, ".");
+        }
+
+        self.path
+            .iter()
+            .try_for_each(|segment| Display::fmt(segment, f))
+    }
+}
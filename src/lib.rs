@@ -71,6 +71,23 @@
 //! let owner = ArcRefA::unwrap_owner(arcref); // recover the owner
 //! ```
 //!
+//! An owner wrapped in [Waitable] can be waited on until the other references are
+//! dropped, for example to reclaim the owner on shutdown by [ArcRef::wait_unwrap_owner()]
+//! or the asynchronous [ArcRef::unwrap_owner_when_unique()]. The references wrapped in
+//! [Lease] wake the waiters right away when they drop. The drops of the plain references
+//! are not notified, and the waiters notice them by re-checking the owner periodically.
+//!
+//! ```
+//! # use ownref::{ArcRefA, Waitable};
+//! # use std::{thread, time::Duration};
+//! let arcref = ArcRefA::new(Waitable::new(['a', 'b']));
+//! let first = ArcRefA::into_lease(arcref.clone().map(|owner| &owner[0]));
+//!
+//! thread::spawn(move || assert_eq!(**first, 'a'));
+//! let owner = ArcRefA::wait_unwrap_owner(arcref, Duration::from_secs(10)).unwrap();
+//! assert_eq!(owner, ['a', 'b']);
+//! ```
+//!
 //! # Data type transformation
 //!
 //! The family of methods `map()`, `filter_map()` and `try_map()` can transform the data type.
@@ -132,6 +149,339 @@
 //! assert!(ref1 == ref2); // equalized by content
 //! ```
 //!
+//! Content ordered references implement [Borrow](std::borrow::Borrow)`<I>`, so that
+//! maps keyed by them can be queried by the data. Address ordered references implement
+//! [Borrow](std::borrow::Borrow)`<Addr<I>>` instead, and the maps are queried by the
+//! address of the data with [Addr::new()].
+//!
+//! [ArcRefHashed] is ordered by content like [ArcRefC], and it caches the hash of the data
+//! for large hash map keys. It does not implement [Borrow](std::borrow::Borrow)`<I>`, so
+//! the hash map is queried with [Hashed] keys, which borrow the data with its hash.
+//!
+//! ```
+//! # use ownref::{ArcRefC, ArcRefHashed, Hashed};
+//! # use std::collections::HashSet;
+//! let text: ArcRefC<String, str> =
+//!     ArcRefC::new("a large key".to_string()).map(|text| text.as_str());
+//! let key: ArcRefHashed<String, str> = text.clone().into();
+//!
+//! let set = HashSet::from([key]);
+//! assert!(set.contains(&ArcRefHashed::from(text)));
+//! assert!(set.contains(&Hashed::new("a large key")));
+//! ```
+//!
+//! # Mutable access
+//!
+//! [ArcRef] is projected by shared references and its data is never mutable.
+//! [ArcRefMut] is projected by mutable references while the owner is not shared.
+//! The data can be mutated by [ArcRefMut::get_mut()] whenever the strong count is one
+//! and the weak count is zero.
+//!
+//! ```
+//! # use ownref::{ArcRefMutC, ArcRefC};
+//! struct Owner {
+//!     a: u8,
+//!     b: f32,
+//! }
+//!
+//! let mut b: ArcRefMutC<Owner, f32> = ArcRefMutC::new(Owner { a: 7, b: 3.14 })
+//!     .map(|owner| &mut owner.b)
+//!     .unwrap_or_else(|_| unreachable!());
+//! *ArcRefMutC::get_mut(&mut b).unwrap() = 2.71;
+//!
+//! let cloned = b.clone();
+//! assert!(ArcRefMutC::get_mut(&mut b).is_none()); // the owner is shared
+//! drop(cloned);
+//!
+//! let b: ArcRefC<Owner, f32> = ArcRefMutC::into_arc_ref(b);
+//! assert_eq!(*b, 2.71);
+//! ```
+//!
+//! # Freezing unique owners
+//!
+//! Converting a [BoxRef] to an [ArcRef] moves the owner to a new allocation with the
+//! reference counts. [UniqueArc] allocates the owner along with the reference counts from
+//! the start, so that [BoxRef::freeze()] shares the owner without moving it.
+//!
+//! ```
+//! # use ownref::{ArcRefC, BoxRefC, UniqueArc};
+//! let mut count: BoxRefC<UniqueArc<[u32; 2]>, u32> =
+//!     BoxRefC::new(UniqueArc::new([1, 2])).map(|counts| &mut counts[1]);
+//! *count += 1;
+//!
+//! let count: ArcRefC<[u32; 2], u32> = BoxRefC::freeze(count);
+//! assert_eq!(*count, 3);
+//! ```
+//!
+//! # Static data
+//!
+//! [MaybeArcRef] refers to either the data within an owner or the static data without
+//! an owner, so that literals need not be allocated in an [Arc](std::sync::Arc).
+//! [ArcRef::from_static()] builds it from a `&'static` reference at no runtime cost.
+//!
+//! ```
+//! # use ownref::{ArcRefC, MaybeArcRefC};
+//! let loaded: MaybeArcRefC<String, str> =
+//!     MaybeArcRefC::new(String::from("loaded")).map(|text| text.as_str());
+//! let literal: MaybeArcRefC<String, str> = ArcRefC::from_static("literal");
+//! assert!(MaybeArcRefC::is_static(&literal));
+//! assert!(literal < loaded); // compared by content
+//! ```
+//!
+//! # Byte buffers
+//!
+//! A slice reference can be narrowed and split into references sharing the same owner,
+//! similar to `Bytes` from the bytes crate. [ArcRefCursor] implements
+//! [Read](std::io::Read) and [BufRead](std::io::BufRead) on the bytes referred by
+//! `ArcRef<O, [u8]>`, and [BoxRefCursor] implements [Write](std::io::Write) on the bytes
+//! referred by `BoxRef<O, [u8]>`.
+//!
+//! ```
+//! # use ownref::{ArcRefC, ArcRefCursorC};
+//! # use std::io::Read;
+//! let bytes = ArcRefC::<Vec<u8>, [u8]>::read_from(&b"\x02abcd"[..]).unwrap();
+//! let mut cursor = ArcRefCursorC::new(bytes);
+//! let mut len = [0u8];
+//! cursor.read_exact(&mut len).unwrap();
+//!
+//! let mut bytes = cursor.remaining();
+//! let payload = ArcRefC::split_to(&mut bytes, len[0] as usize);
+//! assert_eq!(*payload, *b"ab");
+//! assert_eq!(*bytes, *b"cd");
+//! ```
+//!
+//! # Slices
+//!
+//! A slice reference provides the common slice operations, such as [ArcRef::get()],
+//! [ArcRef::chunks()] and [ArcRef::binary_search_by()], returning references that share
+//! the same owner. A [BoxRef] to a slice can be split into [ArcChunkMut] chunks, which
+//! are mutated independently, for example on different threads.
+//!
+//! ```
+//! # use ownref::{ArcChunkMutC, BoxRefC};
+//! let data: BoxRefC<Vec<u32>, [u32]> = BoxRefC::new(vec![1, 2, 3, 4, 5]).map(|vec| &mut vec[..]);
+//! let chunks: Vec<ArcChunkMutC<Vec<u32>, [u32]>> = BoxRefC::chunks_mut(data, 2).collect();
+//!
+//! let last = std::thread::scope(|scope| {
+//!     chunks
+//!         .into_iter()
+//!         .map(|mut chunk| {
+//!             scope.spawn(move || {
+//!                 chunk.iter_mut().for_each(|value| *value *= 10);
+//!                 chunk
+//!             })
+//!         })
+//!         .collect::<Vec<_>>()
+//!         .into_iter()
+//!         .map(|handle| handle.join().unwrap())
+//!         .last()
+//!         .unwrap()
+//! });
+//! assert_eq!(
+//!     ArcChunkMutC::try_unwrap_owner(last).unwrap(),
+//!     [10, 20, 30, 40, 50]
+//! );
+//! ```
+//!
+//! # Arena allocation
+//!
+//! [Arena] is an append-only owner, which allocates values in place and hands out
+//! references sharing the ownership of the arena. It saves an [Arc](std::sync::Arc)
+//! allocation per value, and the values keep distinct addresses for `ArcRefA`.
+//!
+//! ```
+//! # use ownref::{ArcRefA, Arena};
+//! # use std::sync::Arc;
+//! let arena = Arc::new(Arena::new());
+//! let a: ArcRefA<Arena<String>, String> = arena.alloc(String::from("node"));
+//! let b: ArcRefA<Arena<String>, String> = arena.alloc(String::from("node"));
+//! assert_ne!(a, b);
+//!
+//! drop(arena);
+//! assert_eq!(ArcRefA::owner(&a).len(), 2);
+//! ```
+//!
+//! # String interning
+//!
+//! [Interner] stores strings in shared chunks and returns `ArcRef` handles to them. Equal
+//! strings from the same interner share the same address, so the address ordered `ArcRefA`
+//! handles serve as symbols with fast comparison.
+//!
+//! ```
+//! # use ownref::{ArcRefA, Chunk, Interner};
+//! let interner = Interner::new();
+//! let a: ArcRefA<Chunk, str> = interner.intern("ident");
+//! let b: ArcRefA<Chunk, str> = interner.intern(&String::from("ident"));
+//! assert_eq!(a, b);
+//! assert_eq!(interner.len(), 1);
+//! ```
+//!
+//! # Atomic reload
+//!
+//! [AtomicArcRef] holds an [ArcRef] that can be loaded and replaced atomically, which
+//! allows publishing a projection that is reloaded at runtime without locks on readers.
+//! The writers wait only for the readers that started before them.
+//!
+//! ```
+//! # use ownref::{ArcRefC, AtomicArcRefC};
+//! let current = AtomicArcRefC::new(ArcRefC::new(vec![1, 2]).map(|vec| &vec[0]));
+//! let reader = current.load();
+//!
+//! current.store(ArcRefC::new(vec![3, 4]).map(|vec| &vec[1]));
+//! assert_eq!(*reader, 1);
+//! assert_eq!(*current.load(), 4);
+//!
+//! current.rcu(|prev| ArcRefC::new(vec![*prev.clone() + 1]).map(|vec| &vec[0]));
+//! assert_eq!(*current.load(), 5);
+//! ```
+//!
+//! # Tree navigation
+//!
+//! [ArcCursor] navigates a tree within an owner. It remembers the path from the root,
+//! so that it can move to the parent and siblings besides the children. The node type
+//! provides the children by implementing the [Children] trait.
+//!
+//! ```
+//! # use ownref::{ArcCursorC, ArcRefC, Children};
+//! struct Node {
+//!     value: u32,
+//!     children: Vec<Node>,
+//! }
+//!
+//! impl Children for Node {
+//!     fn children(&self) -> impl Iterator<Item = &Self> {
+//!         self.children.iter()
+//!     }
+//! }
+//!
+//! let leaf = |value| Node {
+//!     value,
+//!     children: vec![],
+//! };
+//! let tree = Node {
+//!     value: 0,
+//!     children: vec![leaf(1), leaf(2)],
+//! };
+//! let root: ArcCursorC<Node, Node> = ArcCursorC::new(ArcRefC::new(tree));
+//!
+//! let first = root.children().next().unwrap();
+//! let second = first.next_sibling().unwrap();
+//! assert_eq!(second.node().value, 2);
+//! assert_eq!(second.parent().unwrap().node().value, 0);
+//!
+//! let values: Vec<u32> = root.depth_first().map(|node| node.value).collect();
+//! assert_eq!(values, [0, 1, 2]);
+//! ```
+//!
+//! # Text
+//!
+//! A `str` reference can be split, trimmed and searched into references sharing the
+//! same owner. The delimiters are described by the [Delimiter] trait, which is
+//! implemented for the same types as the unstable `Pattern` trait.
+//!
+//! ```
+//! # use ownref::ArcRefC;
+//! let text: ArcRefC<String, str> =
+//!     ArcRefC::new(String::from("a = 1\nb = 2")).map(|text| text.as_str());
+//! let entries: Vec<(ArcRefC<String, str>, ArcRefC<String, str>)> = ArcRefC::lines(text)
+//!     .filter_map(|line| ArcRefC::split_once(line, '='))
+//!     .map(|(key, value)| (ArcRefC::trim(key), ArcRefC::trim(value)))
+//!     .collect();
+//! assert_eq!(&*entries[1].1, "2");
+//! ```
+//!
+//! # Serialization
+//!
+//! With the `serde` feature, the smart references implement `Serialize` by serializing
+//! the data, in the same way as [Debug](std::fmt::Debug) and [Display](std::fmt::Display).
+//! `ArcOwned::deserialize_with()` and `BoxOwned::deserialize_with()` deserialize the data
+//! borrowing from an owned buffer, which is kept alive along with the data.
+//!
+//! ```ignore
+//! let text = String::from(r#"{"name": "server"}"#);
+//! let config: ArcOwnedC<String, Config<'_>> =
+//!     ArcOwnedC::deserialize_with(text, |text| serde_json::from_str(text))?;
+//! ```
+//!
+//! # Checked projections
+//!
+//! A projection is expected to refer to the data within the owner, or on the heap
+//! memory reachable from the owner. [ArcRef::is_within_owner()] and
+//! [BoxRef::is_within_owner()] tell whether the data lies within the memory of the owner,
+//! which is required by offset-based operations like [BoxRef::deep_clone()].
+//!
+//! With the `checked` feature, `map`, `try_map`, `filter_map`, `flatten` and `flat_map`
+//! verify the projected data and print a warning with the call site if it refers to the
+//! static memory, for example, `ArcRef::map(|_| &SOME_STATIC)`. Such data is valid only if
+//! the owner refers to it, so it is reported rather than rejected. The static memory is
+//! located on Linux only, and the check is a no-op on other platforms.
+//!
+//! ```
+//! # use ownref::ArcRefC;
+//! let owner = ArcRefC::new(([1u8, 2], vec![3u8, 4]));
+//! assert!(ArcRefC::is_within_owner(
+//!     &owner.clone().map(|(array, _)| array)
+//! ));
+//! assert!(!ArcRefC::is_within_owner(
+//!     &owner.map(|(_, vec)| vec.as_slice())
+//! ));
+//! ```
+//!
+//! # Field projections
+//!
+//! [ArcField] and [BoxField] locate the data by a byte offset within the owner
+//! instead of a reference. The offset is described by a [Field], which is
+//! built by the [field!] macro. Fields can be composed, and re-applied to
+//! another owner.
+//!
+//! ```
+//! # use ownref::{field, ArcFieldC, ArcRefC, Field};
+//! # use std::sync::Arc;
+//! #[derive(Clone)]
+//! struct Owner {
+//!     a: u8,
+//!     b: (f32, f64),
+//! }
+//!
+//! let field: Field<Owner, f64> = field!(Owner, b.1);
+//! let owner = Arc::new(Owner {
+//!     a: 7,
+//!     b: (3.14, 2.71),
+//! });
+//!
+//! let x: ArcFieldC<Owner, f64> = ArcFieldC::from_field(owner.clone(), field);
+//! assert_eq!(*x, 2.71);
+//!
+//! // apply to a cloned owner
+//! let y = ArcFieldC::with_owner(&x, Arc::new((*owner).clone()));
+//! assert_eq!(x, y);
+//!
+//! // convert to ArcRef
+//! let z: ArcRefC<Owner, f64> = ArcFieldC::into_arc_ref(y);
+//! assert_eq!(*z, 2.71);
+//! ```
+//!
+//! # Named projections
+//!
+//! The [Project] trait abstracts the projection over [ArcRef], [BoxRef] and
+//! their owned variants. With the `derive` feature, `#[derive(Project)]` on
+//! an owner type `Owner` generates the trait `OwnerProject` with one method
+//! per field, which is available on every smart reference to `Owner`.
+//!
+//! ```ignore
+//! use ownref::{ArcRefC, BoxRefC, Project};
+//!
+//! #[derive(Project)]
+//! struct Owner {
+//!     name: String,
+//!     id: u32,
+//! }
+//!
+//! let name: ArcRefC<Owner, String> = ArcRefC::new(Owner { name: "x".into(), id: 1 }).name();
+//! let mut id: BoxRefC<Owner, u32> = BoxRefC::new(Owner { name: "x".into(), id: 1 }).id();
+//! *id += 1;
+//! ```
+//!
 //! # Iterator flattening
 //!
 //! [ArcRef] is able to flatten the referenced data if the data type can be turned into an iterator.
@@ -158,6 +508,36 @@
 //! let values: Vec<ArcOwnedC<_, &usize>> = own.flat_map(|map| map.values()).collect();
 //! ```
 //!
+//! Each flattened item clones the [Arc](std::sync::Arc) with an atomic operation. For large
+//! collections, [LocalArcRef] shares one [Arc](std::sync::Arc) among the items with a
+//! non-atomic count. The items are converted back to [ArcRef] before crossing threads.
+//!
+//! ```
+//! # use ownref::{ArcRefC, LocalArcRefC};
+//! let vec: ArcRefC<Vec<u32>> = ArcRefC::new((0..1000).collect());
+//! let items: Vec<LocalArcRefC<Vec<u32>, u32>> = ArcRefC::into_local(vec).flatten().collect();
+//! assert_eq!(LocalArcRefC::strong_count(&items[0]), 1);
+//!
+//! let item: ArcRefC<Vec<u32>, u32> = LocalArcRefC::into_arc_ref(items[7].clone());
+//! std::thread::spawn(move || assert_eq!(*item, 7))
+//!     .join()
+//!     .unwrap();
+//! ```
+//!
+//! If most items are discarded, [ArcRef::iter_views()] and [ArcOwned::iter_views()] yield
+//! [ArcRefView] and [ArcOwnedView], which borrow the owner without touching the reference
+//! count. Only the kept views are promoted to owned references.
+//!
+//! ```
+//! # use ownref::{ArcRefC, ArcRefViewC};
+//! let vec: ArcRefC<Vec<u32>> = ArcRefC::new((0..1000).collect());
+//! let kept: Vec<ArcRefC<Vec<u32>, u32>> = ArcRefC::iter_views(&vec)
+//!     .filter(|value| **value % 100 == 0)
+//!     .map(|view| ArcRefViewC::to_arc_ref(&view))
+//!     .collect();
+//! assert_eq!(ArcRefC::strong_count(&vec), 11);
+//! ```
+//!
 //!
 //! # Owner erasure
 //!
@@ -205,14 +585,116 @@
 //! // so they can be stored in a vec
 //! vec![ref1, ref2];
 //! ```
+//!
+//! The owner can also be erased to an arbitrary trait object by the [coerce_owner!] macro,
+//! while the trait methods remain callable on the owner. With the `unsize` feature,
+//! `coerce_owner_unsize()` on the smart references does the same without the macro.
+//!
+//! ```
+//! # use ownref::{coerce_owner, BoxRefC};
+//! trait Plugin {
+//!     fn name(&self) -> &str;
+//! }
+//!
+//! struct Echo {
+//!     name: String,
+//!     buffer: Vec<u8>,
+//! }
+//!
+//! impl Plugin for Echo {
+//!     fn name(&self) -> &str {
+//!         &self.name
+//!     }
+//! }
+//!
+//! let echo = BoxRefC::new(Echo {
+//!     name: "echo".into(),
+//!     buffer: vec![],
+//! });
+//! let mut buffer: BoxRefC<dyn Plugin, Vec<u8>> =
+//!     coerce_owner!(echo.map(|echo| &mut echo.buffer), dyn Plugin);
+//! buffer.push(1);
+//! assert_eq!(BoxRefC::owner(&buffer).name(), "echo");
+//! ```
+//!
+//! Likewise, the data type can be erased by `map()` with a cast and recovered from
+//! [Any](std::any::Any) by `downcast_inner()`. With the `unsize` feature on the nightly
+//! toolchain, the smart references support the implicit unsizing coercion as well.
+//!
+//! ```
+//! # use ownref::ArcRefA;
+//! # use std::{any::Any, fmt::Debug};
+//! let owner = ArcRefA::new((7u8, String::from("text")));
+//! let data: Vec<ArcRefA<(u8, String), dyn Debug>> = vec![
+//!     owner.clone().map(|(num, _)| num as _),
+//!     owner.clone().map(|(_, text)| text as _),
+//! ];
+//! assert_eq!(format!("{:?}", data), r#"[7, "text"]"#);
+//!
+//! let any: ArcRefA<_, dyn Any> = owner.map(|(num, _)| num as _);
+//! let num: ArcRefA<_, u8> = ArcRefA::downcast_inner(any).unwrap();
+//! assert_eq!(*num, 7);
+//! ```
+
+#![cfg_attr(feature = "unsize", feature(coerce_unsized, ptr_metadata, unsize))]
 
+mod addr;
+mod arc_chunk_mut;
+mod arc_cursor;
+mod arc_field;
 mod arc_owned;
+mod arc_owned_view;
 mod arc_ref;
+mod arc_ref_hashed;
+mod arc_ref_mut;
+mod arc_ref_view;
+mod arena;
+mod atomic_arc_ref;
+mod box_field;
 mod box_owned;
 mod box_ref;
+mod bytes;
+mod coerce;
+mod field;
+mod interner;
+mod lens;
+mod local_arc_ref;
 pub mod marker;
+mod maybe_arc_ref;
+mod project;
+#[cfg(feature = "serde")]
+mod serde_impl;
+mod slice;
+mod text;
+mod unique_arc;
+mod utils;
+mod waitable;
 
+pub use addr::*;
+pub use arc_chunk_mut::*;
+pub use arc_cursor::*;
+pub use arc_field::*;
 pub use arc_owned::*;
+pub use arc_owned_view::*;
 pub use arc_ref::*;
+pub use arc_ref_hashed::*;
+pub use arc_ref_mut::*;
+pub use arc_ref_view::*;
+pub use arena::*;
+pub use atomic_arc_ref::*;
+pub use box_field::*;
 pub use box_owned::*;
 pub use box_ref::*;
+pub use bytes::*;
+pub use coerce::*;
+pub use field::*;
+pub use interner::*;
+pub use lens::*;
+pub use local_arc_ref::*;
+pub use maybe_arc_ref::*;
+#[cfg(feature = "derive")]
+pub use ownref_derive::Project;
+pub use project::*;
+pub use text::*;
+pub use unique_arc::*;
+pub use waitable::*;
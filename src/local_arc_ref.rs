@@ -0,0 +1,379 @@
+use crate::{addr::Addr, arc_ref::ArcRef, marker::*, utils::Caller};
+use std::{
+    borrow::Borrow,
+    cmp, fmt,
+    fmt::{Debug, Display},
+    hash::{Hash, Hasher},
+    marker::PhantomData,
+    ops::Deref,
+    ptr,
+    rc::Rc,
+    sync::Arc,
+};
+
+/// Content ordered thread-local reference to data within an owner in [Arc].
+pub type LocalArcRefC<'a, O, I = O> = LocalArcRef<'a, O, I, ByContent>;
+
+/// Pointer address ordered thread-local reference to data within an owner in [Arc].
+pub type LocalArcRefA<'a, O, I = O> = LocalArcRef<'a, O, I, ByAddress>;
+
+/// Thread-local reference to data within an owner in [Arc].
+///
+/// It behaves like [ArcRef], except that the references share one [Arc] to the owner
+/// through a non-atomic [Rc]. Cloning and flattening it only update the non-atomic
+/// count, which is much cheaper than the atomic count of [Arc] on large collections.
+/// It cannot be sent to other threads, and it is converted back to [ArcRef] by
+/// [LocalArcRef::into_arc_ref()] before crossing threads.
+pub struct LocalArcRef<'a, O, I, E>
+where
+    O: ?Sized,
+    I: ?Sized,
+    E: EqKind,
+{
+    // inner goes before owner so that inner drops before owner
+    pub(crate) _phantom: PhantomData<E>,
+    pub(crate) inner: &'a I,
+    pub(crate) owner: Rc<Arc<O>>,
+}
+
+impl<'a, O, I, E> LocalArcRef<'a, O, I, E>
+where
+    O: ?Sized,
+    I: ?Sized,
+    E: EqKind,
+{
+    /// Build from an [ArcRef], which holds its [Arc] for all local references.
+    pub fn from_arc_ref(from: ArcRef<'a, O, I, E>) -> Self {
+        let ArcRef { owner, inner, .. } = from;
+
+        Self {
+            inner,
+            owner: Rc::new(owner),
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Convert to [ArcRef], which can be sent to other threads.
+    ///
+    /// It takes the [Arc] without touching the atomic count if this is the last local reference.
+    pub fn into_arc_ref(from: LocalArcRef<'a, O, I, E>) -> ArcRef<'a, O, I, E> {
+        let Self { owner, inner, .. } = from;
+        let owner = Rc::try_unwrap(owner).unwrap_or_else(|owner| Arc::clone(&owner));
+
+        ArcRef {
+            owner,
+            inner,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Discard the inner reference and return the owner in [Arc].
+    pub fn into_arc(from: LocalArcRef<'a, O, I, E>) -> Arc<O> {
+        ArcRef::into_arc(Self::into_arc_ref(from))
+    }
+
+    /// Get the reference to the owner.
+    pub fn owner(this: &'a LocalArcRef<'a, O, I, E>) -> &'a O {
+        &this.owner
+    }
+
+    /// Get the strong count on the owner, where all local references count as one.
+    pub fn strong_count(this: &LocalArcRef<'a, O, I, E>) -> usize {
+        Arc::strong_count(&this.owner)
+    }
+
+    /// Get the number of local references sharing the same [Arc].
+    pub fn local_count(this: &LocalArcRef<'a, O, I, E>) -> usize {
+        Rc::strong_count(&this.owner)
+    }
+
+    /// Apply function `f` to the inner reference.
+    #[cfg_attr(feature = "checked", track_caller)]
+    pub fn map<T, F>(self, f: F) -> LocalArcRef<'a, O, T, E>
+    where
+        F: FnOnce(&'a I) -> &'a T,
+        T: ?Sized,
+    {
+        let Self { owner, inner, .. } = self;
+
+        LocalArcRef {
+            owner,
+            inner: Caller::new().check(f(inner)),
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Apply fallible function `f` to the inner reference.
+    #[cfg_attr(feature = "checked", track_caller)]
+    pub fn try_map<Ok, Err, F>(self, f: F) -> Result<LocalArcRef<'a, O, Ok, E>, Err>
+    where
+        F: FnOnce(&'a I) -> Result<&'a Ok, Err>,
+        Ok: ?Sized,
+    {
+        let Self { owner, inner, .. } = self;
+
+        Ok(LocalArcRef {
+            owner,
+            inner: Caller::new().check(f(inner)?),
+            _phantom: PhantomData,
+        })
+    }
+
+    /// Apply function `f` that returns an optional reference to the inner reference.
+    #[cfg_attr(feature = "checked", track_caller)]
+    pub fn filter_map<T, F>(self, f: F) -> Option<LocalArcRef<'a, O, T, E>>
+    where
+        F: FnOnce(&'a I) -> Option<&'a T>,
+        T: ?Sized,
+    {
+        let Self { owner, inner, .. } = self;
+
+        Some(LocalArcRef {
+            owner,
+            inner: Caller::new().check(f(inner)?),
+            _phantom: PhantomData,
+        })
+    }
+
+    /// Flatten the wrapped iterable inner reference into an iterator of wrapped items.
+    #[cfg_attr(feature = "checked", track_caller)]
+    pub fn flatten<T>(self) -> impl Iterator<Item = LocalArcRef<'a, O, T, E>>
+    where
+        &'a I: IntoIterator<Item = &'a T>,
+        T: 'a + ?Sized,
+    {
+        self.flat_map(|inner| inner)
+    }
+
+    /// Apply fucntion `f` to get an iterable type, and flatten it to an iterator of references.
+    #[cfg_attr(feature = "checked", track_caller)]
+    pub fn flat_map<T, C, F>(self, f: F) -> impl Iterator<Item = LocalArcRef<'a, O, T, E>>
+    where
+        F: FnOnce(&'a I) -> C,
+        C: IntoIterator<Item = &'a T>,
+        T: 'a + ?Sized,
+    {
+        let Self { owner, inner, .. } = self;
+        let iter = f(inner);
+        let caller = Caller::new();
+
+        iter.into_iter().map(move |item| LocalArcRef {
+            owner: owner.clone(),
+            inner: caller.check(item),
+            _phantom: PhantomData,
+        })
+    }
+}
+
+impl<'a, O, I, E> Clone for LocalArcRef<'a, O, I, E>
+where
+    O: ?Sized,
+    I: ?Sized,
+    E: EqKind,
+{
+    /// Copy the inner reference and increase reference count to owner.
+    fn clone(&self) -> Self {
+        let Self { owner, inner, .. } = self;
+
+        Self {
+            owner: owner.clone(),
+            inner,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<'a, O, I, E> Debug for LocalArcRef<'a, O, I, E>
+where
+    O: ?Sized,
+    I: ?Sized,
+    I: Debug,
+    E: EqKind,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        self.inner.fmt(f)
+    }
+}
+
+impl<'a, O, I, E> Display for LocalArcRef<'a, O, I, E>
+where
+    O: ?Sized,
+    I: ?Sized,
+    I: Display,
+    E: EqKind,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        self.inner.fmt(f)
+    }
+}
+
+impl<'a, O, I> PartialEq<Self> for LocalArcRef<'a, O, I, ByContent>
+where
+    O: ?Sized,
+    I: ?Sized,
+    I: PartialEq<I>,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.inner.eq(other.inner)
+    }
+}
+
+impl<'a, O, I> Eq for LocalArcRef<'a, O, I, ByContent>
+where
+    I: Eq,
+    O: ?Sized,
+    I: ?Sized,
+{
+}
+
+impl<'a, O, I> PartialOrd<Self> for LocalArcRef<'a, O, I, ByContent>
+where
+    O: ?Sized,
+    I: ?Sized,
+    I: PartialOrd<I>,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
+        self.inner.partial_cmp(other.inner)
+    }
+}
+
+impl<'a, O, I> Ord for LocalArcRef<'a, O, I, ByContent>
+where
+    O: ?Sized,
+    I: ?Sized,
+    I: Ord,
+{
+    fn cmp(&self, other: &Self) -> cmp::Ordering {
+        self.inner.cmp(other.inner)
+    }
+}
+
+impl<'a, O, I> Hash for LocalArcRef<'a, O, I, ByContent>
+where
+    O: ?Sized,
+    I: ?Sized + Hash,
+{
+    fn hash<H>(&self, state: &mut H)
+    where
+        H: Hasher,
+    {
+        self.inner.hash(state);
+    }
+}
+
+impl<'a, O, I> PartialEq<Self> for LocalArcRef<'a, O, I, ByAddress>
+where
+    O: ?Sized,
+    I: ?Sized,
+{
+    fn eq(&self, other: &Self) -> bool {
+        ptr::eq(self.inner as *const I, other.inner as *const I)
+    }
+}
+
+impl<'a, O, I> Eq for LocalArcRef<'a, O, I, ByAddress>
+where
+    O: ?Sized,
+    I: ?Sized,
+{
+}
+
+impl<'a, O, I> PartialOrd<Self> for LocalArcRef<'a, O, I, ByAddress>
+where
+    O: ?Sized,
+    I: ?Sized,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<'a, O, I> Ord for LocalArcRef<'a, O, I, ByAddress>
+where
+    O: ?Sized,
+    I: ?Sized,
+{
+    #[allow(ambiguous_wide_pointer_comparisons)]
+    fn cmp(&self, other: &Self) -> cmp::Ordering {
+        (self.inner as *const I).cmp(&(other.inner as *const I))
+    }
+}
+
+impl<'a, O, I> Hash for LocalArcRef<'a, O, I, ByAddress>
+where
+    O: ?Sized,
+    I: ?Sized,
+{
+    fn hash<H>(&self, state: &mut H)
+    where
+        H: Hasher,
+    {
+        ptr::hash(self.inner as *const I, state);
+    }
+}
+
+impl<'a, O, I, E> AsRef<I> for LocalArcRef<'a, O, I, E>
+where
+    O: ?Sized,
+    I: ?Sized,
+    E: EqKind,
+{
+    fn as_ref(&self) -> &I {
+        self.deref()
+    }
+}
+
+impl<'a, O, I> Borrow<I> for LocalArcRef<'a, O, I, ByContent>
+where
+    O: ?Sized,
+    I: ?Sized,
+{
+    fn borrow(&self) -> &I {
+        self.deref()
+    }
+}
+
+impl<'a, O, I> Borrow<Addr<I>> for LocalArcRef<'a, O, I, ByAddress>
+where
+    O: ?Sized,
+    I: ?Sized,
+{
+    fn borrow(&self) -> &Addr<I> {
+        Addr::new(self.inner)
+    }
+}
+
+impl<'a, O, I, E> Deref for LocalArcRef<'a, O, I, E>
+where
+    O: ?Sized,
+    I: ?Sized,
+    E: EqKind,
+{
+    type Target = I;
+
+    fn deref(&self) -> &Self::Target {
+        self.inner
+    }
+}
+
+impl<'a, O, I, E> From<ArcRef<'a, O, I, E>> for LocalArcRef<'a, O, I, E>
+where
+    O: ?Sized,
+    I: ?Sized,
+    E: EqKind,
+{
+    fn from(from: ArcRef<'a, O, I, E>) -> Self {
+        Self::from_arc_ref(from)
+    }
+}
+
+impl<'a, O, I, E> From<LocalArcRef<'a, O, I, E>> for ArcRef<'a, O, I, E>
+where
+    O: ?Sized,
+    I: ?Sized,
+    E: EqKind,
+{
+    fn from(from: LocalArcRef<'a, O, I, E>) -> Self {
+        LocalArcRef::into_arc_ref(from)
+    }
+}
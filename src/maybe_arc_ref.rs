@@ -0,0 +1,372 @@
+use crate::{addr::Addr, arc_ref::ArcRef, marker::*};
+use std::{
+    borrow::Borrow,
+    cmp, fmt,
+    fmt::{Debug, Display},
+    hash::{Hash, Hasher},
+    marker::PhantomData,
+    ops::Deref,
+    ptr,
+    sync::Arc,
+};
+
+/// Content ordered reference to either static data or data within an owner in [Arc].
+pub type MaybeArcRefC<'a, O, I = O> = MaybeArcRef<'a, O, I, ByContent>;
+
+/// Pointer address ordered reference to either static data or data within an owner in [Arc].
+pub type MaybeArcRefA<'a, O, I = O> = MaybeArcRef<'a, O, I, ByAddress>;
+
+/// Reference to either static data or data within an owner in [Arc].
+///
+/// It behaves like [ArcRef], except that it can be built from a `&'static`
+/// reference by [MaybeArcRef::from_static()] without an owner nor allocation.
+/// The owner is optional and it has the same size as [ArcRef].
+pub struct MaybeArcRef<'a, O, I, E>
+where
+    O: ?Sized,
+    I: ?Sized,
+    E: EqKind,
+{
+    // inner goes before owner so that inner drops before owner
+    pub(crate) _phantom: PhantomData<E>,
+    pub(crate) inner: &'a I,
+    pub(crate) owner: Option<Arc<O>>,
+}
+
+impl<'a, O, E> MaybeArcRef<'a, O, O, E>
+where
+    O: ?Sized,
+    E: EqKind,
+{
+    /// Build from owner data in [Arc].
+    pub fn from_arc(owner: Arc<O>) -> Self {
+        owner.into()
+    }
+}
+
+impl<'a, O, I, E> MaybeArcRef<'a, O, I, E>
+where
+    O: ?Sized,
+    I: ?Sized,
+    E: EqKind,
+{
+    /// Build from static data without an owner.
+    pub const fn from_static(inner: &'static I) -> Self {
+        Self {
+            inner,
+            owner: None,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Check if the reference refers to static data without an owner.
+    pub fn is_static(this: &MaybeArcRef<'a, O, I, E>) -> bool {
+        this.owner.is_none()
+    }
+
+    /// Get the reference to the owner if any.
+    pub fn owner(this: &'a MaybeArcRef<'a, O, I, E>) -> Option<&'a O> {
+        this.owner.as_deref()
+    }
+
+    /// Discard the inner reference and return the owner in [Arc] if any.
+    pub fn into_arc(from: MaybeArcRef<'a, O, I, E>) -> Option<Arc<O>> {
+        let Self { owner, .. } = from;
+        owner
+    }
+
+    /// Convert to [ArcRef] if the owner exists, or return the static reference otherwise.
+    pub fn into_arc_ref(this: MaybeArcRef<'a, O, I, E>) -> Result<ArcRef<'a, O, I, E>, &'a I> {
+        let Self { owner, inner, .. } = this;
+
+        match owner {
+            Some(owner) => Ok(ArcRef {
+                owner,
+                inner,
+                _phantom: PhantomData,
+            }),
+            None => Err(inner),
+        }
+    }
+
+    /// Get the strong count on the owner, or `None` for static data.
+    pub fn strong_count(this: &MaybeArcRef<'a, O, I, E>) -> Option<usize> {
+        this.owner.as_ref().map(Arc::strong_count)
+    }
+
+    /// Apply function `f` to the inner reference.
+    pub fn map<T, F>(self, f: F) -> MaybeArcRef<'a, O, T, E>
+    where
+        F: FnOnce(&'a I) -> &'a T,
+        T: ?Sized,
+    {
+        let Self { owner, inner, .. } = self;
+
+        MaybeArcRef {
+            owner,
+            inner: f(inner),
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Apply fallible function `f` to the inner reference.
+    pub fn try_map<Ok, Err, F>(self, f: F) -> Result<MaybeArcRef<'a, O, Ok, E>, Err>
+    where
+        F: FnOnce(&'a I) -> Result<&'a Ok, Err>,
+        Ok: ?Sized,
+    {
+        let Self { owner, inner, .. } = self;
+
+        Ok(MaybeArcRef {
+            owner,
+            inner: f(inner)?,
+            _phantom: PhantomData,
+        })
+    }
+
+    /// Apply function `f` that returns an optional reference to the inner reference.
+    pub fn filter_map<T, F>(self, f: F) -> Option<MaybeArcRef<'a, O, T, E>>
+    where
+        F: FnOnce(&'a I) -> Option<&'a T>,
+        T: ?Sized,
+    {
+        let Self { owner, inner, .. } = self;
+
+        Some(MaybeArcRef {
+            owner,
+            inner: f(inner)?,
+            _phantom: PhantomData,
+        })
+    }
+}
+
+impl<'a, O, I, E> MaybeArcRef<'a, O, I, E>
+where
+    E: EqKind,
+{
+    /// Build from an owner.
+    pub fn new(owner: O) -> Self
+    where
+        Self: From<Arc<O>>,
+    {
+        Arc::new(owner).into()
+    }
+}
+
+impl<'a, O, I, E> Clone for MaybeArcRef<'a, O, I, E>
+where
+    O: ?Sized,
+    I: ?Sized,
+    E: EqKind,
+{
+    /// Copy the inner reference and increase reference count to owner.
+    fn clone(&self) -> Self {
+        let Self { owner, inner, .. } = self;
+
+        Self {
+            owner: owner.clone(),
+            inner,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<'a, O, I, E> Debug for MaybeArcRef<'a, O, I, E>
+where
+    O: ?Sized,
+    I: ?Sized,
+    I: Debug,
+    E: EqKind,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        self.inner.fmt(f)
+    }
+}
+
+impl<'a, O, I, E> Display for MaybeArcRef<'a, O, I, E>
+where
+    O: ?Sized,
+    I: ?Sized,
+    I: Display,
+    E: EqKind,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        self.inner.fmt(f)
+    }
+}
+
+impl<'a, O, I> PartialEq<Self> for MaybeArcRef<'a, O, I, ByContent>
+where
+    O: ?Sized,
+    I: ?Sized,
+    I: PartialEq<I>,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.inner.eq(other.inner)
+    }
+}
+
+impl<'a, O, I> Eq for MaybeArcRef<'a, O, I, ByContent>
+where
+    I: Eq,
+    O: ?Sized,
+    I: ?Sized,
+{
+}
+
+impl<'a, O, I> PartialOrd<Self> for MaybeArcRef<'a, O, I, ByContent>
+where
+    O: ?Sized,
+    I: ?Sized,
+    I: PartialOrd<I>,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
+        self.inner.partial_cmp(other.inner)
+    }
+}
+
+impl<'a, O, I> Ord for MaybeArcRef<'a, O, I, ByContent>
+where
+    O: ?Sized,
+    I: ?Sized,
+    I: Ord,
+{
+    fn cmp(&self, other: &Self) -> cmp::Ordering {
+        self.inner.cmp(other.inner)
+    }
+}
+
+impl<'a, O, I> Hash for MaybeArcRef<'a, O, I, ByContent>
+where
+    O: ?Sized,
+    I: ?Sized + Hash,
+{
+    fn hash<H>(&self, state: &mut H)
+    where
+        H: Hasher,
+    {
+        self.inner.hash(state);
+    }
+}
+
+impl<'a, O, I> PartialEq<Self> for MaybeArcRef<'a, O, I, ByAddress>
+where
+    O: ?Sized,
+    I: ?Sized,
+{
+    fn eq(&self, other: &Self) -> bool {
+        ptr::eq(self.inner as *const I, other.inner as *const I)
+    }
+}
+
+impl<'a, O, I> Eq for MaybeArcRef<'a, O, I, ByAddress>
+where
+    O: ?Sized,
+    I: ?Sized,
+{
+}
+
+impl<'a, O, I> PartialOrd<Self> for MaybeArcRef<'a, O, I, ByAddress>
+where
+    O: ?Sized,
+    I: ?Sized,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<'a, O, I> Ord for MaybeArcRef<'a, O, I, ByAddress>
+where
+    O: ?Sized,
+    I: ?Sized,
+{
+    #[allow(ambiguous_wide_pointer_comparisons)]
+    fn cmp(&self, other: &Self) -> cmp::Ordering {
+        (self.inner as *const I).cmp(&(other.inner as *const I))
+    }
+}
+
+impl<'a, O, I> Hash for MaybeArcRef<'a, O, I, ByAddress>
+where
+    O: ?Sized,
+    I: ?Sized,
+{
+    fn hash<H>(&self, state: &mut H)
+    where
+        H: Hasher,
+    {
+        ptr::hash(self.inner as *const I, state);
+    }
+}
+
+impl<'a, O, I, E> AsRef<I> for MaybeArcRef<'a, O, I, E>
+where
+    O: ?Sized,
+    I: ?Sized,
+    E: EqKind,
+{
+    fn as_ref(&self) -> &I {
+        self.deref()
+    }
+}
+
+impl<'a, O, I> Borrow<I> for MaybeArcRef<'a, O, I, ByContent>
+where
+    O: ?Sized,
+    I: ?Sized,
+{
+    fn borrow(&self) -> &I {
+        self.deref()
+    }
+}
+
+impl<'a, O, I> Borrow<Addr<I>> for MaybeArcRef<'a, O, I, ByAddress>
+where
+    O: ?Sized,
+    I: ?Sized,
+{
+    fn borrow(&self) -> &Addr<I> {
+        Addr::new(self.inner)
+    }
+}
+
+impl<'a, O, I, E> Deref for MaybeArcRef<'a, O, I, E>
+where
+    O: ?Sized,
+    I: ?Sized,
+    E: EqKind,
+{
+    type Target = I;
+
+    fn deref(&self) -> &Self::Target {
+        self.inner
+    }
+}
+
+impl<'a, O, E> From<Arc<O>> for MaybeArcRef<'a, O, O, E>
+where
+    O: ?Sized,
+    E: EqKind,
+{
+    fn from(owner: Arc<O>) -> Self {
+        ArcRef::from(owner).into()
+    }
+}
+
+impl<'a, O, I, E> From<ArcRef<'a, O, I, E>> for MaybeArcRef<'a, O, I, E>
+where
+    O: ?Sized,
+    I: ?Sized,
+    E: EqKind,
+{
+    fn from(from: ArcRef<'a, O, I, E>) -> Self {
+        let ArcRef { owner, inner, .. } = from;
+
+        Self {
+            inner,
+            owner: Some(owner),
+            _phantom: PhantomData,
+        }
+    }
+}
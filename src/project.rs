@@ -0,0 +1,161 @@
+use crate::{
+    arc_owned::ArcOwned, arc_ref::ArcRef, box_owned::BoxOwned, box_ref::BoxRef, marker::*,
+};
+
+/// Common trait for smart references that can be projected to a part of the data.
+///
+/// It abstracts over [ArcRef], [BoxRef], [ArcOwned] of a reference and [BoxOwned]
+/// of a mutable reference, so that projections can be written once for all of
+/// them. Shared references use the function `f`, while mutable references use
+/// the function `f_mut`.
+///
+/// The `Project` derive macro, which is enabled by the `derive` feature,
+/// generates named projections on top of this trait.
+pub trait Project<'a>: Sized {
+    /// The data type to be projected.
+    type Target: ?Sized + 'a;
+
+    /// The smart reference type to the projected data `T`.
+    type Output<T>
+    where
+        T: ?Sized + 'a;
+
+    /// Project the data by `f` for shared references, or by `f_mut` for mutable references.
+    fn project<T, F, G>(self, f: F, f_mut: G) -> Self::Output<T>
+    where
+        T: ?Sized + 'a,
+        F: FnOnce(&'a Self::Target) -> &'a T,
+        G: FnOnce(&'a mut Self::Target) -> &'a mut T;
+
+    /// Project the data by optional `f` for shared references, or by optional `f_mut` for
+    /// mutable references.
+    fn filter_project<T, F, G>(self, f: F, f_mut: G) -> Option<Self::Output<T>>
+    where
+        T: ?Sized + 'a,
+        F: FnOnce(&'a Self::Target) -> Option<&'a T>,
+        G: FnOnce(&'a mut Self::Target) -> Option<&'a mut T>;
+}
+
+impl<'a, O, I, E> Project<'a> for ArcRef<'a, O, I, E>
+where
+    O: ?Sized,
+    I: ?Sized + 'a,
+    E: EqKind,
+{
+    type Target = I;
+    type Output<T>
+        = ArcRef<'a, O, T, E>
+    where
+        T: ?Sized + 'a;
+
+    fn project<T, F, G>(self, f: F, _f_mut: G) -> Self::Output<T>
+    where
+        T: ?Sized + 'a,
+        F: FnOnce(&'a I) -> &'a T,
+        G: FnOnce(&'a mut I) -> &'a mut T,
+    {
+        self.map(f)
+    }
+
+    fn filter_project<T, F, G>(self, f: F, _f_mut: G) -> Option<Self::Output<T>>
+    where
+        T: ?Sized + 'a,
+        F: FnOnce(&'a I) -> Option<&'a T>,
+        G: FnOnce(&'a mut I) -> Option<&'a mut T>,
+    {
+        self.filter_map(f)
+    }
+}
+
+impl<'a, O, I, E> Project<'a> for BoxRef<'a, O, I, E>
+where
+    O: ?Sized,
+    I: ?Sized + 'a,
+    E: EqKind,
+{
+    type Target = I;
+    type Output<T>
+        = BoxRef<'a, O, T, E>
+    where
+        T: ?Sized + 'a;
+
+    fn project<T, F, G>(self, _f: F, f_mut: G) -> Self::Output<T>
+    where
+        T: ?Sized + 'a,
+        F: FnOnce(&'a I) -> &'a T,
+        G: FnOnce(&'a mut I) -> &'a mut T,
+    {
+        self.map(f_mut)
+    }
+
+    fn filter_project<T, F, G>(self, _f: F, f_mut: G) -> Option<Self::Output<T>>
+    where
+        T: ?Sized + 'a,
+        F: FnOnce(&'a I) -> Option<&'a T>,
+        G: FnOnce(&'a mut I) -> Option<&'a mut T>,
+    {
+        self.filter_map(f_mut)
+    }
+}
+
+impl<'a, O, I, E> Project<'a> for ArcOwned<'a, O, &'a I, E>
+where
+    O: ?Sized,
+    I: ?Sized + 'a,
+    E: EqKind,
+{
+    type Target = I;
+    type Output<T>
+        = ArcOwned<'a, O, &'a T, E>
+    where
+        T: ?Sized + 'a;
+
+    fn project<T, F, G>(self, f: F, _f_mut: G) -> Self::Output<T>
+    where
+        T: ?Sized + 'a,
+        F: FnOnce(&'a I) -> &'a T,
+        G: FnOnce(&'a mut I) -> &'a mut T,
+    {
+        self.map(f)
+    }
+
+    fn filter_project<T, F, G>(self, f: F, _f_mut: G) -> Option<Self::Output<T>>
+    where
+        T: ?Sized + 'a,
+        F: FnOnce(&'a I) -> Option<&'a T>,
+        G: FnOnce(&'a mut I) -> Option<&'a mut T>,
+    {
+        self.filter_map(f)
+    }
+}
+
+impl<'a, O, I, E> Project<'a> for BoxOwned<'a, O, &'a mut I, E>
+where
+    O: ?Sized,
+    I: ?Sized + 'a,
+    E: EqKind,
+{
+    type Target = I;
+    type Output<T>
+        = BoxOwned<'a, O, &'a mut T, E>
+    where
+        T: ?Sized + 'a;
+
+    fn project<T, F, G>(self, _f: F, f_mut: G) -> Self::Output<T>
+    where
+        T: ?Sized + 'a,
+        F: FnOnce(&'a I) -> &'a T,
+        G: FnOnce(&'a mut I) -> &'a mut T,
+    {
+        self.map(f_mut)
+    }
+
+    fn filter_project<T, F, G>(self, _f: F, f_mut: G) -> Option<Self::Output<T>>
+    where
+        T: ?Sized + 'a,
+        F: FnOnce(&'a I) -> Option<&'a T>,
+        G: FnOnce(&'a mut I) -> Option<&'a mut T>,
+    {
+        self.filter_map(f_mut)
+    }
+}
@@ -0,0 +1,155 @@
+use crate::{
+    arc_field::ArcField, arc_owned::ArcOwned, arc_ref::ArcRef, arc_ref_mut::ArcRefMut,
+    box_field::BoxField, box_owned::BoxOwned, box_ref::BoxRef, marker::*,
+    maybe_arc_ref::MaybeArcRef,
+};
+use serde::{Deserialize, Serialize, Serializer};
+use std::ops::Deref;
+
+impl<'a, O, I, E> Serialize for ArcRef<'a, O, I, E>
+where
+    O: ?Sized,
+    I: ?Sized + Serialize,
+    E: EqKind,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.deref().serialize(serializer)
+    }
+}
+
+impl<'a, O, I, E> Serialize for ArcRefMut<'a, O, I, E>
+where
+    O: ?Sized,
+    I: ?Sized + Serialize,
+    E: EqKind,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.deref().serialize(serializer)
+    }
+}
+
+impl<'a, O, I, E> Serialize for MaybeArcRef<'a, O, I, E>
+where
+    O: ?Sized,
+    I: ?Sized + Serialize,
+    E: EqKind,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.deref().serialize(serializer)
+    }
+}
+
+impl<'a, O, I, E> Serialize for BoxRef<'a, O, I, E>
+where
+    O: ?Sized,
+    I: ?Sized + Serialize,
+    E: EqKind,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.deref().serialize(serializer)
+    }
+}
+
+impl<'a, O, I, E> Serialize for ArcOwned<'a, O, I, E>
+where
+    O: ?Sized,
+    I: Serialize,
+    E: EqKind,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.deref().serialize(serializer)
+    }
+}
+
+impl<'a, O, I, E> Serialize for BoxOwned<'a, O, I, E>
+where
+    O: ?Sized,
+    I: Serialize,
+    E: EqKind,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.deref().serialize(serializer)
+    }
+}
+
+impl<O, I, E> Serialize for ArcField<O, I, E>
+where
+    I: Serialize,
+    E: EqKind,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.deref().serialize(serializer)
+    }
+}
+
+impl<O, I, E> Serialize for BoxField<O, I, E>
+where
+    I: Serialize,
+    E: EqKind,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.deref().serialize(serializer)
+    }
+}
+
+impl<'a, O, I, E> ArcOwned<'a, O, I, E>
+where
+    O: 'a,
+    I: Deserialize<'a>,
+    E: EqKind,
+{
+    /// Deserialize the data that borrows from the owner by function `f`.
+    ///
+    /// The function `f` is usually the deserialization function of a format,
+    /// for example, `serde_json::from_str`. It avoids leaking the owner buffer
+    /// to deserialize the data that borrows from it.
+    pub fn deserialize_with<F, Err>(owner: O, f: F) -> Result<Self, Err>
+    where
+        F: FnOnce(&'a O) -> Result<I, Err>,
+    {
+        ArcOwned::new(owner).try_map(f)
+    }
+}
+
+impl<'a, O, I, E> BoxOwned<'a, O, I, E>
+where
+    O: 'a,
+    I: Deserialize<'a>,
+    E: EqKind,
+{
+    /// Deserialize the data that borrows from the owner by function `f`.
+    ///
+    /// The function `f` is usually the deserialization function of a format,
+    /// for example, `serde_json::from_str`. It avoids leaking the owner buffer
+    /// to deserialize the data that borrows from it.
+    pub fn deserialize_with<F, Err>(owner: O, f: F) -> Result<Self, Err>
+    where
+        F: FnOnce(&'a O) -> Result<I, Err>,
+    {
+        BoxOwned::new(owner).try_map(|owner| f(owner))
+    }
+}
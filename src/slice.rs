@@ -0,0 +1,227 @@
+use crate::{
+    arc_chunk_mut::ArcChunkMut, arc_ref::ArcRef, box_ref::BoxRef, marker::*, utils::box_into_arc,
+};
+use std::{
+    cmp,
+    marker::PhantomData,
+    ops::RangeBounds,
+    ptr::{self, NonNull},
+    slice::SliceIndex,
+};
+
+impl<'a, O, T, E> ArcRef<'a, O, [T], E>
+where
+    O: ?Sized,
+    E: EqKind,
+{
+    /// Get a reference to the sub-slice in `range`, which shares the same owner.
+    ///
+    /// # Panic
+    /// The method panics if `range` is out of bounds.
+    pub fn slice<R>(this: &ArcRef<'a, O, [T], E>, range: R) -> ArcRef<'a, O, [T], E>
+    where
+        R: RangeBounds<usize>,
+    {
+        let inner = &this.inner[(range.start_bound().cloned(), range.end_bound().cloned())];
+
+        ArcRef {
+            owner: this.owner.clone(),
+            inner,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Split the slice into two at the index `mid`.
+    ///
+    /// # Panic
+    /// The method panics if `mid > len`.
+    pub fn split_at(this: ArcRef<'a, O, [T], E>, mid: usize) -> (Self, Self) {
+        let Self { owner, inner, .. } = this;
+        let (lhs, rhs) = inner.split_at(mid);
+
+        let lhs = ArcRef {
+            owner: owner.clone(),
+            inner: lhs,
+            _phantom: PhantomData,
+        };
+        let rhs = ArcRef {
+            owner,
+            inner: rhs,
+            _phantom: PhantomData,
+        };
+        (lhs, rhs)
+    }
+
+    /// Split off and return the slice `[0, at)`, and leave `[at, len)` in place.
+    ///
+    /// # Panic
+    /// The method panics if `at > len`.
+    pub fn split_to(this: &mut ArcRef<'a, O, [T], E>, at: usize) -> ArcRef<'a, O, [T], E> {
+        let (lhs, rhs) = this.inner.split_at(at);
+        this.inner = rhs;
+
+        ArcRef {
+            owner: this.owner.clone(),
+            inner: lhs,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Split off and return the slice `[at, len)`, and leave `[0, at)` in place.
+    ///
+    /// # Panic
+    /// The method panics if `at > len`.
+    pub fn split_off(this: &mut ArcRef<'a, O, [T], E>, at: usize) -> ArcRef<'a, O, [T], E> {
+        let (lhs, rhs) = this.inner.split_at(at);
+        this.inner = lhs;
+
+        ArcRef {
+            owner: this.owner.clone(),
+            inner: rhs,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Get a reference to an element or a sub-slice, which shares the same owner.
+    ///
+    /// It returns `None` if `index` is out of bounds.
+    pub fn get<J>(this: &ArcRef<'a, O, [T], E>, index: J) -> Option<ArcRef<'a, O, J::Output, E>>
+    where
+        J: SliceIndex<[T]>,
+    {
+        let inner = this.inner.get(index)?;
+
+        Some(ArcRef {
+            owner: this.owner.clone(),
+            inner,
+            _phantom: PhantomData,
+        })
+    }
+
+    /// Get a reference to the first element, or `None` if the slice is empty.
+    pub fn first(this: &ArcRef<'a, O, [T], E>) -> Option<ArcRef<'a, O, T, E>> {
+        Self::get(this, 0)
+    }
+
+    /// Get a reference to the last element, or `None` if the slice is empty.
+    pub fn last(this: &ArcRef<'a, O, [T], E>) -> Option<ArcRef<'a, O, T, E>> {
+        Self::get(this, this.inner.len().checked_sub(1)?)
+    }
+
+    /// Iterate over references to the elements, which share the same owner.
+    pub fn iter(this: ArcRef<'a, O, [T], E>) -> impl Iterator<Item = ArcRef<'a, O, T, E>> {
+        this.flat_map(|slice| slice.iter())
+    }
+
+    /// Iterate over references to the elements along with their indices.
+    pub fn enumerate(
+        this: ArcRef<'a, O, [T], E>,
+    ) -> impl Iterator<Item = (usize, ArcRef<'a, O, T, E>)> {
+        Self::iter(this).enumerate()
+    }
+
+    /// Iterate over chunks of `chunk_size` elements, which share the same owner.
+    ///
+    /// # Panic
+    /// The method panics if `chunk_size` is zero.
+    pub fn chunks(this: ArcRef<'a, O, [T], E>, chunk_size: usize) -> impl Iterator<Item = Self> {
+        this.flat_map(move |slice| slice.chunks(chunk_size))
+    }
+
+    /// Iterate over overlapping windows of `size` elements, which share the same owner.
+    ///
+    /// # Panic
+    /// The method panics if `size` is zero.
+    pub fn windows(this: ArcRef<'a, O, [T], E>, size: usize) -> impl Iterator<Item = Self> {
+        this.flat_map(move |slice| slice.windows(size))
+    }
+
+    /// Split the slice by elements matching `pred` into references sharing the same owner.
+    ///
+    /// The matched elements are not contained in the sub-slices.
+    pub fn split_by<F>(this: ArcRef<'a, O, [T], E>, pred: F) -> impl Iterator<Item = Self>
+    where
+        F: FnMut(&T) -> bool,
+    {
+        this.flat_map(|slice| slice.split(pred))
+    }
+
+    /// Binary search the sorted slice with the comparator `f`.
+    ///
+    /// It returns the reference to the found element, or the index where a matching
+    /// element could be inserted.
+    pub fn binary_search_by<F>(
+        this: &ArcRef<'a, O, [T], E>,
+        f: F,
+    ) -> Result<ArcRef<'a, O, T, E>, usize>
+    where
+        F: FnMut(&'a T) -> cmp::Ordering,
+    {
+        let index = this.inner.binary_search_by(f)?;
+
+        Ok(ArcRef {
+            owner: this.owner.clone(),
+            inner: &this.inner[index],
+            _phantom: PhantomData,
+        })
+    }
+}
+
+impl<'a, O, T, E> BoxRef<'a, O, [T], E>
+where
+    O: ?Sized,
+    E: EqKind,
+{
+    /// Sort the slice in place.
+    pub fn sort(this: &mut BoxRef<'a, O, [T], E>)
+    where
+        T: Ord,
+    {
+        this.inner.sort();
+    }
+
+    /// Sort the slice in place with the comparator `f`.
+    pub fn sort_by<F>(this: &mut BoxRef<'a, O, [T], E>, f: F)
+    where
+        F: FnMut(&T, &T) -> cmp::Ordering,
+    {
+        this.inner.sort_by(f);
+    }
+
+    /// Reverse the order of elements in place.
+    pub fn reverse(this: &mut BoxRef<'a, O, [T], E>) {
+        this.inner.reverse();
+    }
+
+    /// Split the slice into mutable chunks of `chunk_size` elements.
+    ///
+    /// The owner is moved into an [Arc](std::sync::Arc) shared by the chunks, and it can be recovered
+    /// by [ArcChunkMut::try_unwrap_owner()] once the other chunks are dropped.
+    ///
+    /// # Panic
+    /// The method panics if `chunk_size` is zero.
+    pub fn chunks_mut(
+        this: BoxRef<'a, O, [T], E>,
+        chunk_size: usize,
+    ) -> impl Iterator<Item = ArcChunkMut<'a, O, [T], E>> {
+        assert!(chunk_size != 0, "chunk size must be non-zero");
+
+        let Self { owner, inner, .. } = this;
+        let len = inner.len();
+        let (owner, data) = box_into_arc(owner, inner);
+        let data = data as *mut T;
+
+        (0..len).step_by(chunk_size).map(move |start| {
+            let chunk_len = cmp::min(chunk_size, len - start);
+            let inner = unsafe {
+                NonNull::new_unchecked(ptr::slice_from_raw_parts_mut(data.add(start), chunk_len))
+            };
+
+            ArcChunkMut {
+                owner: owner.clone(),
+                inner,
+                _phantom: PhantomData,
+            }
+        })
+    }
+}
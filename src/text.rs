@@ -0,0 +1,346 @@
+use crate::{arc_owned::ArcOwned, arc_ref::ArcRef, box_ref::BoxRef, marker::*};
+use std::{marker::PhantomData, ops::RangeBounds};
+
+/// Patterns to split and search a [str].
+///
+/// It mirrors the unstable [Pattern](std::str::pattern::Pattern) trait, and is
+/// implemented for the same types.
+pub trait Delimiter: Sized {
+    /// Split `text` by the delimiter.
+    fn split(self, text: &str) -> impl Iterator<Item = &str>;
+
+    /// Split `text` on the first occurrence of the delimiter.
+    fn split_once(self, text: &str) -> Option<(&str, &str)>;
+
+    /// Iterate over the occurrences of the delimiter in `text`.
+    fn matches(self, text: &str) -> impl Iterator<Item = &str>;
+}
+
+impl Delimiter for char {
+    fn split(self, text: &str) -> impl Iterator<Item = &str> {
+        text.split(self)
+    }
+
+    fn split_once(self, text: &str) -> Option<(&str, &str)> {
+        text.split_once(self)
+    }
+
+    fn matches(self, text: &str) -> impl Iterator<Item = &str> {
+        text.matches(self)
+    }
+}
+
+impl Delimiter for &str {
+    fn split(self, text: &str) -> impl Iterator<Item = &str> {
+        text.split(self)
+    }
+
+    fn split_once(self, text: &str) -> Option<(&str, &str)> {
+        text.split_once(self)
+    }
+
+    fn matches(self, text: &str) -> impl Iterator<Item = &str> {
+        text.matches(self)
+    }
+}
+
+impl Delimiter for &String {
+    fn split(self, text: &str) -> impl Iterator<Item = &str> {
+        text.split(self.as_str())
+    }
+
+    fn split_once(self, text: &str) -> Option<(&str, &str)> {
+        text.split_once(self.as_str())
+    }
+
+    fn matches(self, text: &str) -> impl Iterator<Item = &str> {
+        text.matches(self.as_str())
+    }
+}
+
+impl Delimiter for &[char] {
+    fn split(self, text: &str) -> impl Iterator<Item = &str> {
+        text.split(self)
+    }
+
+    fn split_once(self, text: &str) -> Option<(&str, &str)> {
+        text.split_once(self)
+    }
+
+    fn matches(self, text: &str) -> impl Iterator<Item = &str> {
+        text.matches(self)
+    }
+}
+
+impl<const N: usize> Delimiter for [char; N] {
+    fn split(self, text: &str) -> impl Iterator<Item = &str> {
+        text.split(self)
+    }
+
+    fn split_once(self, text: &str) -> Option<(&str, &str)> {
+        text.split_once(self)
+    }
+
+    fn matches(self, text: &str) -> impl Iterator<Item = &str> {
+        text.matches(self)
+    }
+}
+
+impl<F> Delimiter for F
+where
+    F: FnMut(char) -> bool,
+{
+    fn split(self, text: &str) -> impl Iterator<Item = &str> {
+        text.split(self)
+    }
+
+    fn split_once(self, text: &str) -> Option<(&str, &str)> {
+        text.split_once(self)
+    }
+
+    fn matches(self, text: &str) -> impl Iterator<Item = &str> {
+        text.matches(self)
+    }
+}
+
+impl<'a, O, E> ArcRef<'a, O, str, E>
+where
+    O: ?Sized,
+    E: EqKind,
+{
+    /// Split the text by `delimiter` into references sharing the same owner.
+    pub fn split<D>(this: ArcRef<'a, O, str, E>, delimiter: D) -> impl Iterator<Item = Self>
+    where
+        D: Delimiter,
+    {
+        let Self { owner, inner, .. } = this;
+        delimiter.split(inner).map(move |item| ArcRef {
+            owner: owner.clone(),
+            inner: item,
+            _phantom: PhantomData,
+        })
+    }
+
+    /// Split the text by whitespace into references sharing the same owner.
+    pub fn split_whitespace(this: ArcRef<'a, O, str, E>) -> impl Iterator<Item = Self> {
+        this.flat_map(|text| text.split_whitespace())
+    }
+
+    /// Split the text into lines sharing the same owner.
+    pub fn lines(this: ArcRef<'a, O, str, E>) -> impl Iterator<Item = Self> {
+        this.flat_map(|text| text.lines())
+    }
+
+    /// Iterate over the occurrences of `delimiter` in the text.
+    pub fn matches<D>(this: ArcRef<'a, O, str, E>, delimiter: D) -> impl Iterator<Item = Self>
+    where
+        D: Delimiter,
+    {
+        let Self { owner, inner, .. } = this;
+        delimiter.matches(inner).map(move |item| ArcRef {
+            owner: owner.clone(),
+            inner: item,
+            _phantom: PhantomData,
+        })
+    }
+
+    /// Split the text on the first occurrence of `delimiter`.
+    pub fn split_once<D>(this: ArcRef<'a, O, str, E>, delimiter: D) -> Option<(Self, Self)>
+    where
+        D: Delimiter,
+    {
+        let Self { owner, inner, .. } = this;
+        let (lhs, rhs) = delimiter.split_once(inner)?;
+
+        let lhs = ArcRef {
+            owner: owner.clone(),
+            inner: lhs,
+            _phantom: PhantomData,
+        };
+        let rhs = ArcRef {
+            owner,
+            inner: rhs,
+            _phantom: PhantomData,
+        };
+        Some((lhs, rhs))
+    }
+
+    /// Remove the leading and trailing whitespace.
+    pub fn trim(this: ArcRef<'a, O, str, E>) -> Self {
+        this.map(str::trim)
+    }
+
+    /// Remove the leading whitespace.
+    pub fn trim_start(this: ArcRef<'a, O, str, E>) -> Self {
+        this.map(str::trim_start)
+    }
+
+    /// Remove the trailing whitespace.
+    pub fn trim_end(this: ArcRef<'a, O, str, E>) -> Self {
+        this.map(str::trim_end)
+    }
+
+    /// Get a reference to the characters in `range`, which is counted in characters
+    /// instead of bytes.
+    ///
+    /// # Panic
+    /// The method panics if `range` is out of bounds.
+    pub fn slice_chars<R>(this: &ArcRef<'a, O, str, E>, range: R) -> Self
+    where
+        R: RangeBounds<usize>,
+    {
+        let range = char_range(this.inner, range);
+
+        ArcRef {
+            owner: this.owner.clone(),
+            inner: &this.inner[range],
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<'a, O, E> BoxRef<'a, O, str, E>
+where
+    O: ?Sized,
+    E: EqKind,
+{
+    /// Remove the leading and trailing whitespace.
+    pub fn trim(this: BoxRef<'a, O, str, E>) -> Self {
+        this.map(|text| {
+            let start = text.len() - text.trim_start().len();
+            let end = text.trim_end().len();
+            &mut text[start..end.max(start)]
+        })
+    }
+
+    /// Remove the leading whitespace.
+    pub fn trim_start(this: BoxRef<'a, O, str, E>) -> Self {
+        this.map(|text| {
+            let start = text.len() - text.trim_start().len();
+            &mut text[start..]
+        })
+    }
+
+    /// Remove the trailing whitespace.
+    pub fn trim_end(this: BoxRef<'a, O, str, E>) -> Self {
+        this.map(|text| {
+            let end = text.trim_end().len();
+            &mut text[..end]
+        })
+    }
+
+    /// Narrow the reference to the characters in `range`, which is counted in characters
+    /// instead of bytes.
+    ///
+    /// # Panic
+    /// The method panics if `range` is out of bounds.
+    pub fn slice_chars<R>(this: BoxRef<'a, O, str, E>, range: R) -> Self
+    where
+        R: RangeBounds<usize>,
+    {
+        this.map(|text| {
+            let range = char_range(text, range);
+            &mut text[range]
+        })
+    }
+}
+
+impl<'a, O, E> ArcOwned<'a, O, &'a str, E>
+where
+    O: ?Sized,
+    E: EqKind,
+{
+    /// Split the text by `delimiter` into references sharing the same owner.
+    pub fn split<D>(this: ArcOwned<'a, O, &'a str, E>, delimiter: D) -> impl Iterator<Item = Self>
+    where
+        D: Delimiter,
+    {
+        ArcRef::split(ArcOwned::into_arc_ref(this), delimiter).map(ArcRef::into_arc_owned)
+    }
+
+    /// Split the text by whitespace into references sharing the same owner.
+    pub fn split_whitespace(this: ArcOwned<'a, O, &'a str, E>) -> impl Iterator<Item = Self> {
+        ArcRef::split_whitespace(ArcOwned::into_arc_ref(this)).map(ArcRef::into_arc_owned)
+    }
+
+    /// Split the text into lines sharing the same owner.
+    pub fn lines(this: ArcOwned<'a, O, &'a str, E>) -> impl Iterator<Item = Self> {
+        ArcRef::lines(ArcOwned::into_arc_ref(this)).map(ArcRef::into_arc_owned)
+    }
+
+    /// Iterate over the occurrences of `delimiter` in the text.
+    pub fn matches<D>(this: ArcOwned<'a, O, &'a str, E>, delimiter: D) -> impl Iterator<Item = Self>
+    where
+        D: Delimiter,
+    {
+        ArcRef::matches(ArcOwned::into_arc_ref(this), delimiter).map(ArcRef::into_arc_owned)
+    }
+
+    /// Split the text on the first occurrence of `delimiter`.
+    pub fn split_once<D>(this: ArcOwned<'a, O, &'a str, E>, delimiter: D) -> Option<(Self, Self)>
+    where
+        D: Delimiter,
+    {
+        let (lhs, rhs) = ArcRef::split_once(ArcOwned::into_arc_ref(this), delimiter)?;
+        Some((ArcRef::into_arc_owned(lhs), ArcRef::into_arc_owned(rhs)))
+    }
+
+    /// Remove the leading and trailing whitespace.
+    pub fn trim(this: ArcOwned<'a, O, &'a str, E>) -> Self {
+        this.map(|text| text.trim())
+    }
+
+    /// Remove the leading whitespace.
+    pub fn trim_start(this: ArcOwned<'a, O, &'a str, E>) -> Self {
+        this.map(|text| text.trim_start())
+    }
+
+    /// Remove the trailing whitespace.
+    pub fn trim_end(this: ArcOwned<'a, O, &'a str, E>) -> Self {
+        this.map(|text| text.trim_end())
+    }
+
+    /// Get a reference to the characters in `range`, which is counted in characters
+    /// instead of bytes.
+    ///
+    /// # Panic
+    /// The method panics if `range` is out of bounds.
+    pub fn slice_chars<R>(this: &ArcOwned<'a, O, &'a str, E>, range: R) -> Self
+    where
+        R: RangeBounds<usize>,
+    {
+        ArcRef::into_arc_owned(ArcRef::slice_chars(
+            &ArcOwned::into_arc_ref(this.clone()),
+            range,
+        ))
+    }
+}
+
+/// Convert the range in characters to the range in bytes.
+fn char_range<R>(text: &str, range: R) -> std::ops::Range<usize>
+where
+    R: RangeBounds<usize>,
+{
+    use std::ops::Bound::*;
+
+    let byte_offset = |index: usize| {
+        text.char_indices()
+            .map(|(offset, _)| offset)
+            .chain([text.len()])
+            .nth(index)
+            .unwrap_or_else(|| panic!("character index {} is out of bounds", index))
+    };
+    let start = match range.start_bound() {
+        Included(&index) => byte_offset(index),
+        Excluded(&index) => byte_offset(index + 1),
+        Unbounded => 0,
+    };
+    let end = match range.end_bound() {
+        Included(&index) => byte_offset(index + 1),
+        Excluded(&index) => byte_offset(index),
+        Unbounded => text.len(),
+    };
+    assert!(start <= end, "the range starts after it ends");
+
+    start..end
+}
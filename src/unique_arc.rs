@@ -0,0 +1,146 @@
+use std::{
+    fmt,
+    fmt::{Debug, Display},
+    ops::{Deref, DerefMut},
+    sync::Arc,
+};
+
+/// Uniquely owned data in [Arc], which is mutable until it is shared.
+///
+/// The data is allocated along with the reference counts from the start. It is used as
+/// the owner of [BoxRef](crate::BoxRef) and [BoxOwned](crate::BoxOwned), which are
+/// frozen into [ArcRef](crate::ArcRef) and [ArcOwned](crate::ArcOwned) without moving
+/// the data, so that the references into the data stay valid.
+pub struct UniqueArc<O>
+where
+    O: ?Sized,
+{
+    arc: Arc<O>,
+}
+
+// the data is not shared, so it behaves as a Box
+unsafe impl<O> Send for UniqueArc<O> where O: ?Sized + Send {}
+unsafe impl<O> Sync for UniqueArc<O> where O: ?Sized + Sync {}
+
+impl<O> UniqueArc<O> {
+    /// Allocate `value` in [Arc].
+    pub fn new(value: O) -> Self {
+        Self {
+            arc: Arc::new(value),
+        }
+    }
+
+    /// Unwrap the data.
+    pub fn into_inner(this: UniqueArc<O>) -> O {
+        Arc::try_unwrap(this.arc).unwrap_or_else(|_| unreachable!())
+    }
+}
+
+impl<O> UniqueArc<O>
+where
+    O: ?Sized,
+{
+    /// Move the boxed data to a new allocation in [Arc].
+    pub fn from_box(owner: Box<O>) -> Self {
+        Self { arc: owner.into() }
+    }
+
+    /// Build from an [Arc] if it is the only strong reference and there is no weak reference.
+    pub fn try_from_arc(mut arc: Arc<O>) -> Result<Self, Arc<O>> {
+        // Arc::get_mut() locks the weak count, so a concurrent upgrade cannot slip in
+        if Arc::get_mut(&mut arc).is_some() {
+            Ok(Self { arc })
+        } else {
+            Err(arc)
+        }
+    }
+
+    /// Share the data without moving it.
+    pub fn into_arc(this: UniqueArc<O>) -> Arc<O> {
+        this.arc
+    }
+}
+
+impl<O> Debug for UniqueArc<O>
+where
+    O: ?Sized + Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        self.deref().fmt(f)
+    }
+}
+
+impl<O> Display for UniqueArc<O>
+where
+    O: ?Sized + Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        self.deref().fmt(f)
+    }
+}
+
+impl<O> Default for UniqueArc<O>
+where
+    O: Default,
+{
+    fn default() -> Self {
+        Self::new(O::default())
+    }
+}
+
+impl<O> AsRef<O> for UniqueArc<O>
+where
+    O: ?Sized,
+{
+    fn as_ref(&self) -> &O {
+        self.deref()
+    }
+}
+
+impl<O> AsMut<O> for UniqueArc<O>
+where
+    O: ?Sized,
+{
+    fn as_mut(&mut self) -> &mut O {
+        self.deref_mut()
+    }
+}
+
+impl<O> Deref for UniqueArc<O>
+where
+    O: ?Sized,
+{
+    type Target = O;
+
+    fn deref(&self) -> &Self::Target {
+        &self.arc
+    }
+}
+
+impl<O> DerefMut for UniqueArc<O>
+where
+    O: ?Sized,
+{
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        // the pointer from Arc::as_ptr() is allowed to write once the Arc is unique
+        unsafe { &mut *(Arc::as_ptr(&self.arc) as *mut O) }
+    }
+}
+
+impl<O> From<Box<O>> for UniqueArc<O>
+where
+    O: ?Sized,
+{
+    fn from(owner: Box<O>) -> Self {
+        Self::from_box(owner)
+    }
+}
+
+impl<O> From<UniqueArc<O>> for Arc<O>
+where
+    O: ?Sized,
+{
+    fn from(from: UniqueArc<O>) -> Self {
+        UniqueArc::into_arc(from)
+    }
+}
@@ -0,0 +1,146 @@
+#[cfg(feature = "checked")]
+use std::panic::Location;
+use std::{mem, ops::Deref, sync::Arc};
+
+/// Check if the [Arc] is the only strong reference and there is no weak reference.
+pub(crate) fn arc_is_unique<O>(arc: &mut Arc<O>) -> bool
+where
+    O: ?Sized,
+{
+    // Arc::get_mut() locks the weak count, so a concurrent upgrade cannot slip in
+    Arc::get_mut(arc).is_some()
+}
+
+/// Get the byte offset of `inner` to `owner` if `inner` lies within the memory of `owner`.
+pub(crate) fn offset_within<O, I>(owner: &O, inner: &I) -> Option<usize>
+where
+    O: ?Sized,
+    I: ?Sized,
+{
+    let start = owner as *const O as *const u8 as usize;
+    let end = start + mem::size_of_val(owner);
+    let addr = inner as *const I as *const u8 as usize;
+    let len = mem::size_of_val(inner);
+
+    (start <= addr && addr + len <= end).then(|| addr - start)
+}
+
+/// Move the boxed owner into an [Arc], and relocate `inner` if it lies within the owner.
+///
+/// The conversion moves the owner to a new allocation with the reference counts. The data
+/// within the owner moves along with it, while the data on the heap memory reachable from
+/// the owner stays in place.
+pub(crate) fn box_into_arc<O, I>(owner: Box<O>, inner: *mut I) -> (Arc<O>, *mut I)
+where
+    O: ?Sized,
+    I: ?Sized,
+{
+    let offset = offset_within(&*owner, unsafe { &*inner });
+    let owner: Arc<O> = owner.into();
+
+    let inner = match offset {
+        // derive the pointer from the new allocation, since the old one is freed
+        Some(offset) => unsafe { with_addr(inner, (Arc::as_ptr(&owner) as *mut u8).add(offset)) },
+        None => inner,
+    };
+
+    (owner, inner)
+}
+
+/// Replace the address of a possibly wide pointer and keep its metadata.
+///
+/// # Safety
+/// The pointer `addr` must be valid for the data described by the metadata of `ptr`.
+#[cfg(feature = "unsize")]
+unsafe fn with_addr<I>(ptr: *mut I, addr: *mut u8) -> *mut I
+where
+    I: ?Sized,
+{
+    std::ptr::from_raw_parts_mut(addr, std::ptr::metadata(ptr))
+}
+
+/// Replace the address of a possibly wide pointer and keep its metadata.
+///
+/// # Safety
+/// The pointer `addr` must be valid for the data described by the metadata of `ptr`.
+#[cfg(not(feature = "unsize"))]
+unsafe fn with_addr<I>(mut ptr: *mut I, addr: *mut u8) -> *mut I
+where
+    I: ?Sized,
+{
+    // the address is the first word of both thin and wide pointers
+    *(&mut ptr as *mut *mut I as *mut *mut u8) = addr;
+    ptr
+}
+
+/// The call site of a projection, which is recorded in checked mode.
+#[derive(Clone, Copy)]
+pub(crate) struct Caller {
+    #[cfg(feature = "checked")]
+    location: &'static Location<'static>,
+}
+
+impl Caller {
+    /// Record the caller of the current function.
+    #[cfg_attr(feature = "checked", track_caller)]
+    #[inline]
+    pub(crate) fn new() -> Self {
+        Self {
+            #[cfg(feature = "checked")]
+            location: Location::caller(),
+        }
+    }
+
+    /// Verify the projected data `inner` and pass it through.
+    ///
+    /// The data is expected to lie within the owner, or on the memory reachable from the
+    /// owner. The data in the static memory is reported with the call site, but it is not
+    /// rejected, since the owner may refer to it, for example, an owner of `&'static str`.
+    /// It is a no-op unless the `checked` feature is enabled.
+    #[inline]
+    pub(crate) fn check<R>(self, inner: R) -> R
+    where
+        R: Deref,
+    {
+        #[cfg(feature = "checked")]
+        if mem::size_of_val(&*inner) != 0 && is_static(&*inner) {
+            eprintln!(
+                "warning: the projection at {} refers to static data at {:p}, which is valid only if the owner refers to it",
+                self.location,
+                &*inner as *const R::Target as *const u8
+            );
+        }
+
+        inner
+    }
+}
+
+/// Check if the data lies in the static memory of the executable.
+#[cfg(all(feature = "checked", target_os = "linux"))]
+fn is_static<I>(data: &I) -> bool
+where
+    I: ?Sized,
+{
+    // symbols provided by the linker
+    extern "C" {
+        static __ehdr_start: u8;
+        static _end: u8;
+    }
+
+    let start = std::ptr::addr_of!(__ehdr_start) as usize;
+    let end = std::ptr::addr_of!(_end) as usize;
+    let addr = data as *const I as *const u8 as usize;
+
+    start <= addr && addr < end
+}
+
+/// Check if the data lies in the static memory of the executable.
+///
+/// The static memory cannot be located on this platform, so it always returns false.
+#[cfg(all(feature = "checked", not(target_os = "linux")))]
+fn is_static<I>(_data: &I) -> bool
+where
+    I: ?Sized,
+{
+    false
+}
@@ -0,0 +1,389 @@
+use crate::{arc_owned::ArcOwned, arc_ref::ArcRef, marker::*};
+use std::{
+    cmp, fmt,
+    fmt::{Debug, Display},
+    future::Future,
+    marker::PhantomData,
+    mem::{self, ManuallyDrop},
+    ops::{Deref, DerefMut},
+    pin::Pin,
+    sync::{Arc, Condvar, Mutex, MutexGuard},
+    task::{Context, Poll, Waker},
+    thread,
+    time::{Duration, Instant},
+};
+
+/// Owner that can be waited on until the other references to it are released.
+///
+/// The drop of a [Lease] wakes the waiters on the owner right away. The drops of the
+/// plain references, including the clones and projections of a lease, do not notify, and
+/// the waiters notice them by re-checking the owner every 10 milliseconds.
+pub struct Waitable<O>
+where
+    O: ?Sized,
+{
+    signal: Arc<Signal>,
+    data: O,
+}
+
+impl<O> Waitable<O> {
+    /// Wrap the owner data.
+    pub fn new(data: O) -> Self {
+        Self {
+            signal: Arc::new(Signal::default()),
+            data,
+        }
+    }
+
+    /// Unwrap the owner data.
+    pub fn into_inner(this: Waitable<O>) -> O {
+        this.data
+    }
+}
+
+impl<O> Debug for Waitable<O>
+where
+    O: ?Sized + Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        self.data.fmt(f)
+    }
+}
+
+impl<O> Display for Waitable<O>
+where
+    O: ?Sized + Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        self.data.fmt(f)
+    }
+}
+
+impl<O> Default for Waitable<O>
+where
+    O: Default,
+{
+    fn default() -> Self {
+        Self::new(O::default())
+    }
+}
+
+impl<O> Deref for Waitable<O>
+where
+    O: ?Sized,
+{
+    type Target = O;
+
+    fn deref(&self) -> &Self::Target {
+        &self.data
+    }
+}
+
+impl<O> DerefMut for Waitable<O>
+where
+    O: ?Sized,
+{
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.data
+    }
+}
+
+impl<O> From<O> for Waitable<O> {
+    fn from(data: O) -> Self {
+        Self::new(data)
+    }
+}
+
+/// Reference to a [Waitable] owner, which wakes the waiters on the owner when it drops.
+pub struct Lease<H> {
+    signal: Arc<Signal>,
+    handle: ManuallyDrop<H>,
+}
+
+impl<H> Clone for Lease<H>
+where
+    H: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            signal: self.signal.clone(),
+            handle: self.handle.clone(),
+        }
+    }
+}
+
+impl<H> Debug for Lease<H>
+where
+    H: Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        self.handle.fmt(f)
+    }
+}
+
+impl<H> Deref for Lease<H> {
+    type Target = H;
+
+    fn deref(&self) -> &Self::Target {
+        &self.handle
+    }
+}
+
+impl<H> Drop for Lease<H> {
+    fn drop(&mut self) {
+        // release the owner before the notification, so that the waiters see it released
+        unsafe {
+            ManuallyDrop::drop(&mut self.handle);
+        }
+        self.signal.notify();
+    }
+}
+
+impl<'a, O, I, E> ArcRef<'a, Waitable<O>, I, E>
+where
+    I: ?Sized,
+    E: EqKind,
+{
+    /// Wrap the reference in a [Lease], which wakes the waiters on the owner when it drops.
+    pub fn into_lease(this: ArcRef<'a, Waitable<O>, I, E>) -> Lease<Self> {
+        Lease {
+            signal: this.owner.signal.clone(),
+            handle: ManuallyDrop::new(this),
+        }
+    }
+
+    /// Wait until the other references to the owner are released, and then unwrap the owner.
+    ///
+    /// It gives back the reference if the other references are still alive after `timeout`.
+    /// The waiter wakes up right away when a [Lease] drops, and notices the drops of the
+    /// plain references within a short interval.
+    pub fn wait_unwrap_owner(
+        mut from: ArcRef<'a, Waitable<O>, I, E>,
+        timeout: Duration,
+    ) -> Result<O, Self> {
+        let deadline = Instant::now() + timeout;
+
+        loop {
+            from = match Self::try_unwrap(from) {
+                Ok(owner) => return Ok(owner),
+                Err(from) => from,
+            };
+
+            if !wait_unique(&from.owner, deadline) {
+                return Err(from);
+            }
+        }
+    }
+
+    /// Unwrap the owner once the other references to the owner are released.
+    ///
+    /// The task is woken right away when a [Lease] drops, and it notices the drops of the
+    /// plain references within a short interval.
+    pub async fn unwrap_owner_when_unique(mut from: ArcRef<'a, Waitable<O>, I, E>) -> O {
+        loop {
+            from = match Self::try_unwrap(from) {
+                Ok(owner) => return owner,
+                Err(from) => from,
+            };
+
+            WhenUnique { owner: &from.owner }.await;
+        }
+    }
+
+    fn try_unwrap(from: ArcRef<'a, Waitable<O>, I, E>) -> Result<O, Self> {
+        let Self { owner, inner, .. } = from;
+
+        match Arc::try_unwrap(owner) {
+            Ok(owner) => Ok(Waitable::into_inner(owner)),
+            Err(owner) => Err(Self {
+                owner,
+                inner,
+                _phantom: PhantomData,
+            }),
+        }
+    }
+}
+
+impl<'a, O, I, E> ArcOwned<'a, Waitable<O>, I, E>
+where
+    E: EqKind,
+{
+    /// Wrap the reference in a [Lease], which wakes the waiters on the owner when it drops.
+    pub fn into_lease(this: ArcOwned<'a, Waitable<O>, I, E>) -> Lease<Self> {
+        Lease {
+            signal: this.owner.signal.clone(),
+            handle: ManuallyDrop::new(this),
+        }
+    }
+
+    /// Wait until the other references to the owner are released, and then unwrap the owner.
+    ///
+    /// It gives back the reference if the other references are still alive after `timeout`.
+    /// The waiter wakes up right away when a [Lease] drops, and notices the drops of the
+    /// plain references within a short interval.
+    pub fn wait_unwrap_owner(
+        mut from: ArcOwned<'a, Waitable<O>, I, E>,
+        timeout: Duration,
+    ) -> Result<O, Self> {
+        let deadline = Instant::now() + timeout;
+
+        loop {
+            from = match Self::try_unwrap(from) {
+                Ok(owner) => return Ok(owner),
+                Err(from) => from,
+            };
+
+            if !wait_unique(&from.owner, deadline) {
+                return Err(from);
+            }
+        }
+    }
+
+    /// Unwrap the owner once the other references to the owner are released.
+    ///
+    /// The task is woken right away when a [Lease] drops, and it notices the drops of the
+    /// plain references within a short interval.
+    pub async fn unwrap_owner_when_unique(mut from: ArcOwned<'a, Waitable<O>, I, E>) -> O {
+        loop {
+            from = match Self::try_unwrap(from) {
+                Ok(owner) => return owner,
+                Err(from) => from,
+            };
+
+            WhenUnique { owner: &from.owner }.await;
+        }
+    }
+
+    fn try_unwrap(from: ArcOwned<'a, Waitable<O>, I, E>) -> Result<O, Self> {
+        let Self { owner, inner, .. } = from;
+
+        match Arc::try_unwrap(owner) {
+            Ok(owner) => Ok(Waitable::into_inner(owner)),
+            Err(owner) => Err(Self {
+                owner,
+                inner,
+                _phantom: PhantomData,
+            }),
+        }
+    }
+}
+
+/// The interval to re-check the owner, which notices the drops of the plain references.
+const RECHECK_INTERVAL: Duration = Duration::from_millis(10);
+
+/// Wake-ups shared by a [Waitable] owner and its leases.
+#[derive(Default)]
+struct Signal {
+    state: Mutex<State>,
+    cond: Condvar,
+}
+
+#[derive(Default)]
+struct State {
+    wakers: Vec<Waker>,
+    /// Whether the thread re-checking the tasks is running.
+    ticking: bool,
+}
+
+impl Signal {
+    fn notify(&self) {
+        let wakers = {
+            let mut state = lock(&self.state);
+            self.cond.notify_all();
+            mem::take(&mut state.wakers)
+        };
+
+        // wake outside the lock, since the tasks may be polled within the call
+        wakers.into_iter().for_each(Waker::wake);
+    }
+
+    /// Periodically wake the waiting tasks until none is left.
+    fn tick(self: Arc<Self>) {
+        loop {
+            thread::sleep(RECHECK_INTERVAL);
+
+            let wakers = {
+                let mut state = lock(&self.state);
+                if state.wakers.is_empty() {
+                    state.ticking = false;
+                    return;
+                }
+                mem::take(&mut state.wakers)
+            };
+
+            wakers.into_iter().for_each(Waker::wake);
+        }
+    }
+}
+
+/// Block until `owner` is left with one strong reference, or until `deadline`.
+///
+/// It returns false if the deadline is reached. The count is checked under the lock
+/// taken by the notification, so that a [Lease] released in between is not missed, and
+/// re-checked periodically to notice the plain references.
+fn wait_unique<O>(owner: &Arc<Waitable<O>>, deadline: Instant) -> bool
+where
+    O: ?Sized,
+{
+    let signal = &owner.signal;
+    let mut state = lock(&signal.state);
+
+    loop {
+        if Arc::strong_count(owner) == 1 {
+            return true;
+        }
+
+        let now = Instant::now();
+        if now >= deadline {
+            return false;
+        }
+
+        state = signal
+            .cond
+            .wait_timeout(state, cmp::min(deadline - now, RECHECK_INTERVAL))
+            .unwrap_or_else(|err| err.into_inner())
+            .0;
+    }
+}
+
+/// Future that resolves when the owner is left with one strong reference.
+struct WhenUnique<'w, O>
+where
+    O: ?Sized,
+{
+    owner: &'w Arc<Waitable<O>>,
+}
+
+impl<'w, O> Future for WhenUnique<'w, O>
+where
+    O: ?Sized,
+{
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let signal = &self.owner.signal;
+        let mut state = lock(&signal.state);
+
+        if Arc::strong_count(self.owner) == 1 {
+            return Poll::Ready(());
+        }
+
+        if !state.wakers.iter().any(|waker| waker.will_wake(cx.waker())) {
+            state.wakers.push(cx.waker().clone());
+        }
+
+        // re-check periodically, since the plain references do not notify
+        if !state.ticking {
+            state.ticking = true;
+            let signal = signal.clone();
+            thread::spawn(move || signal.tick());
+        }
+
+        Poll::Pending
+    }
+}
+
+fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    // the state is always consistent, so a poisoned lock is fine to use
+    mutex.lock().unwrap_or_else(|err| err.into_inner())
+}
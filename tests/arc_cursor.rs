@@ -0,0 +1,73 @@
+use ownref::{ArcCursorA, ArcCursorC, ArcRefA, ArcRefC, Children};
+
+#[derive(Debug, PartialEq)]
+struct Node {
+    name: &'static str,
+    children: Vec<Node>,
+}
+
+impl Node {
+    fn new(name: &'static str, children: Vec<Node>) -> Self {
+        Self { name, children }
+    }
+}
+
+impl Children for Node {
+    fn children(&self) -> impl Iterator<Item = &Self> {
+        self.children.iter()
+    }
+}
+
+fn tree() -> Node {
+    Node::new(
+        "root",
+        vec![
+            Node::new("a", vec![Node::new("a1", vec![]), Node::new("a2", vec![])]),
+            Node::new("b", vec![Node::new("b1", vec![])]),
+        ],
+    )
+}
+
+#[test]
+fn arc_cursor_navigation() {
+    let root: ArcCursorC<Node, Node> = ArcCursorC::new(ArcRefC::new(tree()));
+    assert!(root.parent().is_none());
+    assert!(root.next_sibling().is_none());
+
+    let a = root.children().next().unwrap();
+    assert_eq!(a.node().name, "a");
+    assert_eq!(a.depth(), 1);
+    assert!(a.prev_sibling().is_none());
+
+    let b = a.next_sibling().unwrap();
+    assert_eq!(b.node().name, "b");
+    assert!(b.next_sibling().is_none());
+    assert_eq!(b.prev_sibling().unwrap().node().name, "a");
+
+    let a2 = a.children().last().unwrap();
+    assert_eq!(a2.node().name, "a2");
+    assert_eq!(a2.depth(), 2);
+    assert_eq!(a2.prev_sibling().unwrap().node().name, "a1");
+    assert_eq!(a2.parent().unwrap().node(), a.node());
+    assert_eq!(a2.root().node().name, "root");
+
+    let a2: ArcRefC<Node, Node> = a2.into_node();
+    assert_eq!(ArcRefC::strong_count(&a2), 4);
+}
+
+#[test]
+fn arc_cursor_traversal() {
+    let root: ArcCursorA<Node, Node> = ArcRefA::new(tree()).into();
+
+    let names: Vec<&str> = root.depth_first().map(|node| node.name).collect();
+    assert_eq!(names, ["root", "a", "a1", "a2", "b", "b1"]);
+
+    let names: Vec<&str> = root.breadth_first().map(|node| node.name).collect();
+    assert_eq!(names, ["root", "a", "b", "a1", "a2", "b1"]);
+
+    let b = root.children().nth(1).unwrap();
+    let nodes: Vec<ArcRefA<Node, Node>> = b.depth_first().collect();
+    assert_eq!(nodes.len(), 2);
+    assert_eq!(nodes[1], b.children().next().unwrap().into_node());
+    assert_eq!(ArcRefA::strong_count(b.node()), 4);
+}
@@ -1,6 +1,8 @@
+#![allow(clippy::explicit_auto_deref, clippy::needless_borrow)]
+
 use indexmap::IndexMap;
-use ownref::{ArcOwnedA, ArcOwnedC};
-use std::collections::HashSet;
+use ownref::{ArcOwnedA, ArcOwnedAnyC, ArcOwnedAnyLocalC, ArcOwnedC};
+use std::{any::TypeId, cell::Cell, collections::HashSet};
 
 #[test]
 fn arc_owned_borrow() {
@@ -97,3 +99,37 @@ fn arc_owned_c() {
     let array: [char; 2] = ArcOwnedC::unwrap_owner(ref_b);
     assert_eq!(array, ['a', 'b']);
 }
+
+#[test]
+fn arc_owned_get_mut() {
+    let mut owner = ArcOwnedA::new(['a', 'b']).map(|array| array[0]);
+    *ArcOwnedA::get_mut(&mut owner).unwrap() = 'c';
+    assert_eq!(*owner, 'c');
+
+    let cloned = owner.clone();
+    assert!(ArcOwnedA::get_mut(&mut owner).is_none());
+    drop(cloned);
+    assert!(ArcOwnedA::get_mut(&mut owner).is_some());
+}
+
+#[test]
+fn arc_owned_any_owner_local() {
+    let x = ArcOwnedC::new([Cell::new(1), Cell::new(2)]);
+    let x = x.map(|array| array[1].get());
+    let x = ArcOwnedC::into_any_owner_local(x);
+    assert_eq!(
+        ArcOwnedAnyLocalC::owner_type_id(&x),
+        TypeId::of::<[Cell<i32>; 2]>()
+    );
+
+    let x: ArcOwnedC<[Cell<i32>; 2], i32> = ArcOwnedC::downcast_owner_local(x)
+        .map_err(|_| ())
+        .expect("unable to downcast");
+    assert_eq!(*x, 2);
+
+    let y = ArcOwnedC::into_any_owner(ArcOwnedC::new(['a', 'b']).map(|array| array[0]));
+    assert_eq!(
+        ArcOwnedAnyC::owner_downcast_ref::<[char; 2]>(&y),
+        Some(&['a', 'b'])
+    );
+}
@@ -1,5 +1,12 @@
-use ownref::{ArcRefA, ArcRefC};
-use std::collections::HashSet;
+#![allow(clippy::explicit_auto_deref)]
+
+use ownref::{Addr, ArcRefA, ArcRefAnyLocalA, ArcRefC};
+use std::{
+    any::TypeId,
+    cell::Cell,
+    collections::{BTreeSet, HashSet},
+    rc::Rc,
+};
 
 #[test]
 fn arc_ref_borrow() {
@@ -7,6 +14,23 @@ fn arc_ref_borrow() {
     assert!(set.contains(&'a'));
 }
 
+#[test]
+fn arc_ref_a_borrow() {
+    let owner = ArcRefA::new(['a', 'a', 'b']);
+    let refs: Vec<ArcRefA<[char; 3], char>> = owner.clone().flatten().collect();
+    let set: HashSet<ArcRefA<[char; 3], char>> = refs[..2].iter().cloned().collect();
+
+    // looked up by address rather than by content
+    assert!(set.contains(Addr::new(&*refs[1])));
+    assert!(!set.contains(Addr::new(&*refs[2])));
+    assert!(!set.contains(Addr::new(&'a')));
+    assert!(set.contains(Addr::new(&owner[0])));
+
+    let set: BTreeSet<ArcRefA<[char; 3], char>> = refs.iter().cloned().collect();
+    assert!(set.contains(Addr::new(&owner[2])));
+    assert_eq!(Addr::new(&owner[1]).as_ptr(), &*refs[1] as *const char);
+}
+
 #[test]
 fn arc_ref_any_owner() {
     let x = ArcRefA::new(['a', 'b']);
@@ -83,3 +107,73 @@ fn arc_ref_c() {
     let array: [char; 2] = ArcRefC::unwrap_owner(ref_b);
     assert_eq!(array, ['a', 'b']);
 }
+
+#[test]
+fn arc_ref_is_within_owner() {
+    let owner = ArcRefC::new((['a', 'b'], vec!['c', 'd']));
+    let array = owner.clone().map(|(array, _)| &array[1]);
+    let vec = owner.map(|(_, vec)| &vec[1]);
+
+    assert!(ArcRefC::is_within_owner(&array));
+    assert!(!ArcRefC::is_within_owner(&vec));
+}
+
+#[cfg(feature = "checked")]
+#[test]
+fn arc_ref_checked_static() {
+    static DATA: [char; 2] = ['a', 'b'];
+
+    // the static data reachable from the owner is accepted
+    let owner: ArcRefC<&'static [char; 2]> = ArcRefC::new(&DATA);
+    let second = owner.map(|data| &data[1]);
+    assert_eq!(*second, 'b');
+}
+
+#[cfg(feature = "checked")]
+#[test]
+fn arc_ref_checked_heap() {
+    let refs: Vec<ArcRefC<Vec<char>, char>> = ArcRefC::new(vec!['a', 'b']).flatten().collect();
+    assert_eq!(*refs[1], 'b');
+}
+
+#[test]
+fn arc_ref_raw() {
+    let x = ArcRefC::new(vec!['a', 'b']).map(|vec| &vec[1]);
+    let (owner, inner) = ArcRefC::into_raw(x);
+
+    unsafe {
+        ArcRefC::<Vec<char>, char>::increment_strong_count(owner);
+        let x: ArcRefC<Vec<char>, char> = ArcRefC::from_raw(owner, inner);
+        assert_eq!(*x, 'b');
+        assert_eq!(ArcRefC::strong_count(&x), 2);
+        drop(x);
+
+        let y: ArcRefC<Vec<char>, char> = ArcRefC::from_raw(owner, inner);
+        assert_eq!(ArcRefC::strong_count(&y), 1);
+
+        let owner = ArcRefC::into_arc(y);
+        let z: ArcRefC<Vec<char>, [char]> = ArcRefC::from_raw_parts(owner.clone(), &owner[..]);
+        assert_eq!(*z, ['a', 'b']);
+    }
+}
+
+#[test]
+fn arc_ref_any_owner_local() {
+    let x = ArcRefA::new((Rc::new(3), Cell::new('a')));
+    let x = x.map(|(_, cell)| cell);
+    let x = ArcRefA::into_any_owner_local(x);
+
+    type Owner = (Rc<i32>, Cell<char>);
+    assert_eq!(ArcRefAnyLocalA::owner_type_id(&x), TypeId::of::<Owner>());
+    assert!(ArcRefAnyLocalA::owner_downcast_ref::<()>(&x).is_none());
+    assert_eq!(
+        *ArcRefAnyLocalA::owner_downcast_ref::<Owner>(&x).unwrap().0,
+        3
+    );
+
+    let x = ArcRefA::downcast_owner_local::<()>(x).expect_err("downcast to a wrong type");
+    let x: ArcRefA<Owner, _> = ArcRefA::downcast_owner_local(x)
+        .map_err(|_| ())
+        .expect("unable to downcast");
+    assert_eq!(x.get(), 'a');
+}
@@ -0,0 +1,60 @@
+use ownref::{ArcRefC, ArcRefHashed, Hashed};
+use std::{
+    collections::{hash_map::DefaultHasher, HashMap},
+    hash::{Hash, Hasher},
+};
+
+fn hash_of<T>(value: &T) -> u64
+where
+    T: Hash + ?Sized,
+{
+    let mut hasher = DefaultHasher::new();
+    value.hash(&mut hasher);
+    hasher.finish()
+}
+
+#[test]
+fn arc_ref_hashed_map() {
+    let doc: ArcRefHashed<Vec<String>> = ArcRefHashed::new(vec![
+        "alpha".to_string(),
+        "beta".to_string(),
+        "alpha".to_string(),
+    ]);
+    assert_eq!(ArcRefHashed::cached_hash(&doc), hash_of(&*doc));
+
+    let keys: Vec<ArcRefHashed<Vec<String>, str>> = doc
+        .clone()
+        .flat_map(|doc| doc.iter().map(|text| text.as_str()))
+        .collect();
+    assert_eq!(ArcRefHashed::cached_hash(&keys[1]), hash_of("beta"));
+    assert_eq!(keys[0], keys[2]);
+    assert_ne!(keys[0], keys[1]);
+    assert!(keys[0] < keys[1]);
+
+    let mut counts: HashMap<ArcRefHashed<Vec<String>, str>, usize> = HashMap::new();
+    keys.into_iter()
+        .for_each(|key| *counts.entry(key).or_default() += 1);
+    assert_eq!(counts.len(), 2);
+    assert_eq!(counts.get(&Hashed::new("beta")), Some(&1));
+    assert_eq!(counts.get(&Hashed::new("gamma")), None);
+
+    let first: ArcRefHashed<Vec<String>, String> = doc.map(|doc| &doc[0]);
+    let first: ArcRefHashed<Vec<String>, str> = first.map(|text| text.as_str());
+    assert_eq!(counts[&first], 2);
+    assert_eq!(ArcRefHashed::strong_count(&first), 3);
+}
+
+#[test]
+fn arc_ref_hashed_convert() {
+    let text: ArcRefC<String, str> = ArcRefC::new("text".to_string()).map(|text| text.as_str());
+    let hashed = ArcRefHashed::from_arc_ref(text.clone());
+    assert_eq!(ArcRefHashed::as_arc_ref(&hashed), &text);
+    assert_eq!(format!("{} {:?}", hashed, hashed), "text \"text\"");
+
+    let text2: ArcRefC<String, str> = hashed.into();
+    assert_eq!(text, text2);
+    assert!(ArcRefHashed::try_map(ArcRefHashed::from(text2), |text| text
+        .parse::<u8>()
+        .map(|_| &0u8))
+    .is_err());
+}
@@ -0,0 +1,57 @@
+use ownref::{ArcRefA, ArcRefMutA, ArcRefMutC};
+use std::sync::Arc;
+
+#[test]
+fn arc_ref_mut_get_mut() {
+    let mut owner = ArcRefMutA::new(['a', 'b']);
+    ArcRefMutA::get_mut(&mut owner).unwrap()[0] = 'c';
+
+    let mut ref_b: ArcRefMutA<[char; 2], char> = owner
+        .map(|array| &mut array[1])
+        .map_err(|_| ())
+        .expect("unable to map");
+    *ArcRefMutA::get_mut(&mut ref_b).unwrap() = 'd';
+
+    let cloned = ref_b.clone();
+    assert!(ArcRefMutA::get_mut(&mut ref_b).is_none());
+    drop(cloned);
+
+    let weak = Arc::downgrade(&ArcRefMutA::into_arc(ref_b.clone()));
+    assert!(ArcRefMutA::get_mut(&mut ref_b).is_none());
+    drop(weak);
+
+    assert!(ArcRefMutA::get_mut(&mut ref_b).is_some());
+    assert_eq!(ArcRefMutA::unwrap_owner(ref_b), ['c', 'd']);
+}
+
+#[test]
+fn arc_ref_mut_map_shared() {
+    let owner = ArcRefMutC::new(['a', 'b']);
+    let cloned = owner.clone();
+
+    let owner = owner
+        .map(|array| &mut array[1])
+        .expect_err("map should fail on shared owner");
+    drop(cloned);
+
+    let ref_b = owner
+        .filter_map(|array| array.get_mut(1))
+        .map_err(|_| ())
+        .expect("unable to map")
+        .unwrap();
+    assert_eq!(*ref_b, 'b');
+}
+
+#[test]
+fn arc_ref_mut_into_arc_ref() {
+    let owner = ArcRefMutA::new(['a', 'b']);
+    let ref_b = owner
+        .map(|array| &mut array[1])
+        .map_err(|_| ())
+        .expect("unable to map");
+    let ptr: *const char = &*ref_b;
+
+    let ref_b: ArcRefA<[char; 2], char> = ArcRefMutA::into_arc_ref(ref_b);
+    assert_eq!(*ref_b, 'b');
+    assert_eq!(&*ref_b as *const char, ptr);
+}
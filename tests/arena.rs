@@ -0,0 +1,52 @@
+use ownref::{ArcRefA, ArcRefC, Arena};
+use std::{sync::Arc, thread};
+
+#[test]
+fn arena_alloc() {
+    let arena = Arc::new(Arena::with_capacity(1));
+    assert!(arena.is_empty());
+
+    let values: Vec<ArcRefC<Arena<Vec<u32>>, Vec<u32>>> =
+        (0..100).map(|value| arena.alloc(vec![value])).collect();
+    assert_eq!(arena.len(), 100);
+    assert!(values
+        .iter()
+        .enumerate()
+        .all(|(index, value)| **value == [index as u32]));
+    assert_eq!(ArcRefC::strong_count(&values[0]), 101);
+
+    let first = values[0].clone();
+    drop(values);
+    drop(arena);
+    assert_eq!(*first, [0]);
+    assert_eq!(ArcRefC::strong_count(&first), 1);
+}
+
+#[test]
+fn arena_concurrent() {
+    let arena: Arc<Arena<String>> = Arc::new(Arena::new());
+
+    let values: Vec<ArcRefA<Arena<String>, String>> = thread::scope(|scope| {
+        let handles: Vec<_> = (0..4)
+            .map(|index| {
+                let arena = &arena;
+                scope.spawn(move || {
+                    (0..50)
+                        .map(|_| arena.alloc(index.to_string()))
+                        .collect::<Vec<ArcRefA<Arena<String>, String>>>()
+                })
+            })
+            .collect();
+        handles
+            .into_iter()
+            .flat_map(|handle| handle.join().unwrap())
+            .collect()
+    });
+
+    assert_eq!(arena.len(), 200);
+    assert_eq!(
+        values.iter().filter(|value| value.as_str() == "3").count(),
+        50
+    );
+    assert_ne!(values[0], values[1]);
+}
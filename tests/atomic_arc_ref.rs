@@ -0,0 +1,113 @@
+use ownref::{ArcRefA, ArcRefC, AtomicArcRefA, AtomicArcRefC};
+use std::{
+    sync::atomic::{AtomicBool, Ordering},
+    thread,
+};
+
+#[derive(Debug)]
+struct Config {
+    name: String,
+    version: usize,
+}
+
+fn config(name: &str, version: usize) -> ArcRefC<'static, Config> {
+    ArcRefC::new(Config {
+        name: name.to_string(),
+        version,
+    })
+}
+
+#[test]
+fn atomic_arc_ref_swap() {
+    let first = config("a", 0);
+    let atomic: AtomicArcRefC<Config, String> = first.clone().map(|config| &config.name).into();
+
+    let name = atomic.load();
+    assert_eq!(*name, "a");
+    assert_eq!(ArcRefC::strong_count(&first), 3);
+
+    let prev = atomic.swap(config("b", 1).map(|config| &config.name));
+    assert_eq!(prev, name);
+    drop(prev);
+    assert_eq!(ArcRefC::strong_count(&first), 2);
+    assert_eq!(*atomic.load(), "b");
+
+    // compared by content
+    let result = atomic.compare_and_swap(&name, config("c", 2).map(|config| &config.name));
+    assert_eq!(*result.unwrap_err(), "c");
+    let expect = config("b", 3).map(|config| &config.name);
+    let result = atomic.compare_and_swap(&expect, config("c", 4).map(|config| &config.name));
+    assert_eq!(*result.unwrap(), "b");
+
+    atomic.store(name);
+    assert_eq!(format!("{:?}", atomic), "\"a\"");
+    let name = atomic.into_inner();
+    assert_eq!(ArcRefC::strong_count(&name), 2);
+}
+
+#[test]
+fn atomic_arc_ref_address() {
+    let first = ArcRefA::new(Config {
+        name: String::from("a"),
+        version: 0,
+    });
+    let atomic: AtomicArcRefA<Config> = first.clone().into();
+
+    // compared by address
+    let same = ArcRefA::new(Config {
+        name: String::from("a"),
+        version: 0,
+    });
+    assert!(atomic.compare_and_swap(&same, same.clone()).is_err());
+    assert!(atomic.compare_and_swap(&first, same.clone()).is_ok());
+    assert_eq!(atomic.load(), same);
+}
+
+#[test]
+fn atomic_arc_ref_concurrent() {
+    let atomic: AtomicArcRefC<Config, usize> = config("a", 0).map(|config| &config.version).into();
+
+    thread::scope(|scope| {
+        for _ in 0..4 {
+            scope.spawn(|| {
+                for _ in 0..100 {
+                    atomic.rcu(|prev| config("a", **prev + 1).map(|config| &config.version));
+                }
+            });
+            scope.spawn(|| {
+                let mut last = 0;
+                for _ in 0..100 {
+                    let version = *atomic.load();
+                    assert!(version >= last);
+                    last = version;
+                }
+            });
+        }
+    });
+
+    assert_eq!(*atomic.load(), 400);
+}
+
+#[test]
+fn atomic_arc_ref_busy_readers() {
+    let atomic: AtomicArcRefC<Config, usize> = config("a", 0).map(|config| &config.version).into();
+    let done = AtomicBool::new(false);
+
+    thread::scope(|scope| {
+        for _ in 0..4 {
+            scope.spawn(|| {
+                while !done.load(Ordering::SeqCst) {
+                    let _ = atomic.load();
+                }
+            });
+        }
+
+        // the writers are not starved by the readers
+        for version in 1..=100 {
+            atomic.store(config("a", version).map(|config| &config.version));
+        }
+        done.store(true, Ordering::SeqCst);
+    });
+
+    assert_eq!(*atomic.load(), 100);
+}
@@ -1,3 +1,5 @@
+#![allow(clippy::explicit_auto_deref, clippy::needless_borrow)]
+
 use ownref::{BoxOwnedA, BoxOwnedC};
 
 #[test]
@@ -53,3 +55,15 @@ fn box_owned_c() {
     let array: [char; 2] = BoxOwnedC::into_owner(ref_b);
     assert_eq!(array, ['a', 'b']);
 }
+
+#[test]
+fn box_owned_deep_clone_with() {
+    let mut refs: BoxOwnedA<[char; 3], Vec<&mut char>> =
+        BoxOwnedA::new(['a', 'b', 'c']).map(|array| array.iter_mut().collect());
+    let snapshot = BoxOwnedA::deep_clone_with(&refs, |array| array.iter_mut().collect());
+    *refs[0] = 'd';
+
+    assert_eq!(*snapshot[0], 'a');
+    assert_eq!(*refs[0], 'd');
+    assert_eq!(BoxOwnedA::into_owner(snapshot), ['a', 'b', 'c']);
+}
@@ -1,4 +1,6 @@
-use ownref::{BoxRefA, BoxRefC};
+#![allow(clippy::explicit_auto_deref)]
+
+use ownref::{ArcOwnedC, ArcRefC, BoxOwnedC, BoxRefA, BoxRefAnyLocalA, BoxRefC};
 
 #[test]
 fn box_ref_any_owner() {
@@ -15,6 +17,10 @@ fn box_ref_any_owner_local() {
     let x = BoxRefA::new(['a', 'b']);
     let x = x.map(|array| &mut array[0]);
     let x = BoxRefA::into_any_owner_local(x);
+    assert_eq!(
+        BoxRefAnyLocalA::owner_downcast_ref::<[char; 2]>(&x),
+        Some(&['a', 'b'])
+    );
     let _: BoxRefA<[char; 2], _> = BoxRefA::downcast_owner_local(x)
         .map_err(|_| ())
         .expect("unable to downcast");
@@ -53,3 +59,77 @@ fn box_ref_c() {
     let array: [char; 2] = BoxRefC::into_owner(ref_b);
     assert_eq!(array, ['a', 'b']);
 }
+
+#[test]
+fn box_ref_deep_clone() {
+    let mut ref_b: BoxRefA<[char; 2], char> = BoxRefA::new(['a', 'b']).map(|array| &mut array[1]);
+    let snapshot = BoxRefA::deep_clone(&ref_b).unwrap();
+    *ref_b = 'c';
+
+    assert_eq!(*snapshot, 'b');
+    assert_eq!(*ref_b, 'c');
+    assert!(snapshot != ref_b);
+    assert_eq!(BoxRefA::into_owner(snapshot), ['a', 'b']);
+    assert_eq!(BoxRefA::into_owner(ref_b), ['a', 'c']);
+}
+
+#[test]
+fn box_ref_deep_clone_outside_owner() {
+    let mut outside = 'c';
+    let ref_c: BoxRefA<[char; 2], char> = BoxRefA::new(['a', 'b']).map(|_| &mut outside);
+
+    // the data does not lie within the copied owner
+    assert!(!BoxRefA::is_within_owner(&ref_c));
+    assert!(BoxRefA::deep_clone(&ref_c).is_none());
+    assert_eq!(*ref_c, 'c');
+}
+
+#[test]
+fn box_ref_deep_clone_with() {
+    let ref_b: BoxRefA<Vec<char>, char> = BoxRefA::new(vec!['a', 'b']).map(|vec| &mut vec[1]);
+
+    let snapshot = BoxRefA::deep_clone_with(&ref_b, |vec| &mut vec[1]);
+    assert_eq!(*snapshot, 'b');
+    assert!(snapshot != ref_b);
+}
+
+#[test]
+fn box_ref_into_arc_ref_within_owner() {
+    let ref_b: BoxRefC<[u64; 4], u64> = BoxRefC::new([1, 2, 3, 4]).map(|array| &mut array[2]);
+    let ref_b: ArcRefC<[u64; 4], u64> = BoxRefC::into_arc_ref(ref_b);
+    assert_eq!(*ref_b, 3);
+    assert!(std::ptr::eq(&*ref_b, &ArcRefC::owner(&ref_b)[2]));
+}
+
+#[test]
+fn box_ref_relocate_field() {
+    struct Owner {
+        name: String,
+        values: [u32; 4],
+    }
+
+    let owner = || Owner {
+        name: "owner".to_string(),
+        values: [1, 2, 3, 4],
+    };
+
+    let slice: BoxRefC<Owner, [u32]> = BoxRefC::new(owner()).map(|owner| &mut owner.values[1..]);
+    let slice: ArcRefC<Owner, [u32]> = BoxRefC::into_arc_ref(slice);
+    assert_eq!(*slice, [2, 3, 4]);
+    assert!(std::ptr::eq(&slice[0], &ArcRefC::owner(&slice).values[1]));
+
+    let slice: BoxRefC<Owner, [u32]> = BoxRefC::new(owner()).map(|owner| &mut owner.values[..2]);
+    let slice: ArcOwnedC<Owner, &mut [u32]> = BoxRefC::into_arc_owned(slice);
+    assert_eq!(**slice, [1, 2]);
+    assert_eq!(ArcOwnedC::owner(&slice).name, "owner");
+
+    let text: BoxOwnedC<Owner, &mut String> = BoxOwnedC::new(owner()).map(|owner| &mut owner.name);
+    let text: ArcRefC<Owner, String> = text.into_arc_ref();
+    assert_eq!(*text, "owner");
+    assert!(std::ptr::eq(&*text, &ArcRefC::owner(&text).name));
+
+    let slice: BoxRefC<Owner, [u32]> = BoxRefC::new(owner()).map(|owner| &mut owner.values[..]);
+    let chunks: Vec<_> = BoxRefC::chunks_mut(slice, 3).collect();
+    assert_eq!(*chunks[0], [1, 2, 3]);
+    assert_eq!(*chunks[1], [4]);
+}
@@ -0,0 +1,50 @@
+use ownref::{ArcRefA, ArcRefC, ArcRefCursorC, BoxRefC, BoxRefCursorC};
+use std::io::{BufRead, Read, Write};
+
+#[test]
+fn bytes_split() {
+    let mut bytes: ArcRefA<Vec<u8>, [u8]> =
+        ArcRefA::new(vec![0, 1, 2, 3, 4, 5]).map(|vec| &vec[..]);
+
+    let slice = ArcRefA::slice(&bytes, 1..=2);
+    assert_eq!(*slice, [1, 2]);
+    assert_eq!(ArcRefA::strong_count(&bytes), 2);
+
+    let head = ArcRefA::split_to(&mut bytes, 2);
+    assert_eq!(*head, [0, 1]);
+    assert_eq!(*bytes, [2, 3, 4, 5]);
+
+    let tail = ArcRefA::split_off(&mut bytes, 3);
+    assert_eq!(*tail, [5]);
+    assert_eq!(*bytes, [2, 3, 4]);
+
+    let (lhs, rhs) = ArcRefA::split_at(bytes, 1);
+    assert_eq!(*lhs, [2]);
+    assert_eq!(*rhs, [3, 4]);
+    assert_eq!(ArcRefA::slice(&rhs, ..), ArcRefA::slice(&rhs, 0..2));
+}
+
+#[test]
+fn bytes_io() {
+    let bytes = ArcRefC::<Vec<u8>, [u8]>::read_from(&b"head\nbody"[..]).unwrap();
+    let mut reader = ArcRefCursorC::new(bytes);
+
+    let mut line = String::new();
+    reader.read_line(&mut line).unwrap();
+    assert_eq!(line, "head\n");
+
+    let mut buf = [0; 2];
+    reader.read_exact(&mut buf).unwrap();
+    assert_eq!(&buf, b"bo");
+    assert_eq!(reader.position(), 7);
+    assert_eq!(*reader.remaining(), *b"dy");
+    assert_eq!(*reader.into_inner(), *b"head\nbody");
+
+    let writer: BoxRefC<Vec<u8>, [u8]> = BoxRefC::new(vec![0; 4]).map(|vec| &mut vec[1..]);
+    let mut writer = BoxRefCursorC::new(writer);
+    writer.write_all(&[1, 2]).unwrap();
+    assert_eq!(writer.position(), 2);
+    assert!(writer.write_all(&[3, 4]).is_err());
+    assert_eq!(**writer.get_ref(), [1, 2, 3]);
+    assert_eq!(*BoxRefC::into_box(writer.into_inner()), [0, 1, 2, 3]);
+}
@@ -1,5 +1,5 @@
-use ownref::{ArcOwnedA, ArcRefA, BoxOwnedA, BoxRefA};
-use std::sync::Arc;
+use ownref::{coerce_owner, ArcOwnedA, ArcRefA, BoxOwnedA, BoxRefA};
+use std::{any::Any, fmt::Debug, sync::Arc};
 
 #[test]
 fn box_convert() {
@@ -27,3 +27,139 @@ fn arc_convert() {
     assert_eq!(**own2, *ref_);
     assert_eq!(**own, **own2);
 }
+
+trait Plugin {
+    fn id(&self) -> u32;
+}
+
+struct Counter {
+    id: u32,
+    counts: [u64; 2],
+}
+
+impl Plugin for Counter {
+    fn id(&self) -> u32 {
+        self.id
+    }
+}
+
+#[test]
+fn coerce_owner() {
+    let count: ArcRefA<Counter, u64> = ArcRefA::new(Counter {
+        id: 3,
+        counts: [5, 8],
+    })
+    .map(|counter| &counter.counts[1]);
+    let count: ArcRefA<dyn Plugin + Send + Sync, u64> =
+        coerce_owner!(count, dyn Plugin + Send + Sync);
+    assert_eq!(ArcRefA::owner(&count).id(), 3);
+    assert_eq!(*count, 8);
+
+    let own: BoxOwnedA<Counter, u64> = BoxOwnedA::new(Counter {
+        id: 4,
+        counts: [5, 8],
+    })
+    .map(|counter| counter.counts[0]);
+    let own: BoxOwnedA<dyn Plugin, u64> = coerce_owner!(own, dyn Plugin);
+    assert_eq!(BoxOwnedA::owner(&own).id(), 4);
+    assert_eq!(*own, 5);
+
+    let counts = BoxRefA::new(Counter {
+        id: 5,
+        counts: [5, 8],
+    })
+    .map(|counter| &mut counter.counts);
+    let mut counts: BoxRefA<dyn Plugin, [u64; 2]> = coerce_owner!(counts, dyn Plugin);
+    counts[0] += 1;
+    assert_eq!(BoxRefA::owner(&counts).id(), 5);
+    assert_eq!(*counts, [6, 8]);
+
+    let id = ArcOwnedA::new(Counter {
+        id: 6,
+        counts: [5, 8],
+    })
+    .map(|counter| counter.id);
+    let id: ArcOwnedA<dyn Plugin + Send + Sync, u32> = coerce_owner!(id, dyn Plugin + Send + Sync);
+    assert_eq!(ArcOwnedA::owner(&id).id(), *id);
+}
+
+trait Node: Debug {
+    fn len(&self) -> usize;
+}
+
+#[derive(Debug)]
+struct Text(String);
+
+#[derive(Debug)]
+struct List(Vec<u8>);
+
+impl Node for Text {
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+}
+
+impl Node for List {
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+}
+
+struct Doc {
+    title: Text,
+    items: List,
+}
+
+#[test]
+fn map_to_trait_object() {
+    let doc = ArcRefA::new(Doc {
+        title: Text("title".into()),
+        items: List(vec![1, 2]),
+    });
+    let nodes: Vec<ArcRefA<Doc, dyn Node>> = vec![
+        doc.clone().map(|doc| &doc.title as _),
+        doc.map(|doc| &doc.items as _),
+    ];
+    assert_eq!(nodes[0].len(), 5);
+    assert_eq!(nodes[1].len(), 2);
+
+    let mut list = BoxRefA::new(List(vec![1])).map::<dyn Any, _>(|list| list as _);
+    list = BoxRefA::downcast_inner::<Text>(list).expect_err("downcast to a wrong type");
+    let mut list: BoxRefA<List> = BoxRefA::downcast_inner(list).unwrap();
+    list.0.push(2);
+    assert_eq!(list.0, [1, 2]);
+
+    let own: ArcOwnedA<List, &dyn Debug> = ArcOwnedA::new(List(vec![3])).map(|list| list as _);
+    assert_eq!(format!("{:?}", own), "List([3])");
+
+    let own: ArcOwnedA<List, &dyn Any> = ArcOwnedA::new(List(vec![4])).map(|list| list as _);
+    let own = ArcOwnedA::downcast_inner::<Text>(own).expect_err("downcast to a wrong type");
+    let own: ArcOwnedA<List, &List> = ArcOwnedA::downcast_inner(own).unwrap();
+    assert_eq!(own.0, [4]);
+
+    let own: BoxOwnedA<List, &mut dyn Any> = BoxOwnedA::new(List(vec![5])).map(|list| list as _);
+    let own = BoxOwnedA::downcast_inner::<Text>(own).expect_err("downcast to a wrong type");
+    let mut own: BoxOwnedA<List, &mut List> = BoxOwnedA::downcast_inner(own).unwrap();
+    own.0.push(6);
+    assert_eq!(own.0, [5, 6]);
+}
+
+#[cfg(feature = "unsize")]
+#[test]
+fn coerce_unsized() {
+    let any: ArcRefA<Text, dyn Any> = ArcRefA::new(Text("text".into()));
+    let text: ArcRefA<Text, Text> = ArcRefA::downcast_inner(any).unwrap();
+    assert_eq!(text.0, "text");
+
+    let own: BoxOwnedA<List, &mut dyn Node> = BoxOwnedA::new(List(vec![1]));
+    assert_eq!(own.len(), 1);
+
+    let count = ArcRefA::new(Counter {
+        id: 3,
+        counts: [5, 8],
+    })
+    .map(|counter| &counter.counts[1]);
+    let count: ArcRefA<dyn Plugin + Send + Sync, u64> = ArcRefA::coerce_owner_unsize(count);
+    assert_eq!(ArcRefA::owner(&count).id(), 3);
+    assert_eq!(*count, 8);
+}
@@ -0,0 +1,78 @@
+use ownref::{field, ArcFieldA, ArcFieldC, ArcRefA, BoxFieldA, BoxFieldC, BoxRefA, Field};
+use std::{mem, sync::Arc};
+
+#[derive(Debug, Clone, PartialEq)]
+struct Header {
+    id: u32,
+    name: [char; 2],
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Owner {
+    size: usize,
+    header: Header,
+}
+
+fn owner() -> Owner {
+    Owner {
+        size: 3,
+        header: Header {
+            id: 7,
+            name: ['a', 'b'],
+        },
+    }
+}
+
+#[test]
+fn field_compose() {
+    let name: Field<Owner, [char; 2]> = field!(Owner, header.name);
+    let header: Field<Owner, Header> = field!(Owner, header);
+    assert_eq!(name, header.then(field!(Header, name)));
+    assert_eq!(Field::identity().then(name), name);
+
+    let mut owner = owner();
+    assert_eq!(name.get(&owner), &['a', 'b']);
+    name.get_mut(&mut owner)[0] = 'c';
+    assert_eq!(owner.header.name, ['c', 'b']);
+}
+
+#[test]
+fn arc_field() {
+    assert_eq!(
+        mem::size_of::<ArcFieldA<Owner, u32>>(),
+        2 * mem::size_of::<usize>()
+    );
+
+    let owner = Arc::new(owner());
+    let id: ArcFieldC<Owner, u32> =
+        ArcFieldC::from_arc(owner.clone()).map(field!(Owner, header.id));
+    assert_eq!(*id, 7);
+
+    let cloned = ArcFieldC::with_owner(&id, Arc::new((*owner).clone()));
+    assert_eq!(id, cloned);
+
+    let id: ArcFieldA<Owner, u32> = ArcFieldA::from_field(owner.clone(), field!(Owner, header.id));
+    let cloned = ArcFieldA::with_owner(&id, Arc::new((*owner).clone()));
+    assert!(id != cloned);
+    assert_eq!(id, id.clone());
+
+    let ref_: ArcRefA<Owner, u32> = ArcFieldA::into_arc_ref(id);
+    assert!(std::ptr::eq(&*ref_, &owner.header.id));
+}
+
+#[test]
+fn box_field() {
+    let mut name: BoxFieldC<Owner, [char; 2]> =
+        BoxFieldC::new(owner()).map(field!(Owner, header.name));
+    name[1] = 'c';
+
+    let cloned = BoxFieldC::deep_clone(&name);
+    assert_eq!(name, cloned);
+    assert_eq!(BoxFieldC::into_owner(cloned).header.name, ['a', 'c']);
+
+    let name: BoxFieldA<Owner, [char; 2]> =
+        BoxFieldA::from_field(Box::new(owner()), field!(Owner, header.name));
+    let mut ref_: BoxRefA<Owner, [char; 2]> = BoxFieldA::into_box_ref(name);
+    ref_[0] = 'd';
+    assert_eq!(BoxRefA::into_owner(ref_).header.name, ['d', 'b']);
+}
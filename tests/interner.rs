@@ -0,0 +1,62 @@
+use ownref::{ArcRefA, ArcRefC, Chunk, Interner};
+use std::thread;
+
+#[test]
+fn interner_intern() {
+    let interner = Interner::with_chunk_size(8);
+    assert!(interner.is_empty());
+
+    let a: ArcRefA<Chunk, str> = interner.intern("abc");
+    let b: ArcRefA<Chunk, str> = interner.intern("defghi");
+    let c: ArcRefA<Chunk, str> = interner.intern("abc");
+    let long: ArcRefA<Chunk, str> = interner.intern("a long identifier");
+    assert_eq!(a, c);
+    assert_ne!(a, b);
+    assert_eq!(&*b, "defghi");
+    assert_eq!(&*long, "a long identifier");
+    assert_eq!(interner.len(), 3);
+
+    // "defghi" does not fit in the first chunk
+    assert!(!std::ptr::eq(ArcRefA::owner(&a), ArcRefA::owner(&b)));
+    assert_eq!(ArcRefA::strong_count(&a), 3);
+
+    let content: ArcRefC<Chunk, str> = interner.get("abc").unwrap();
+    assert_eq!(content.as_ptr(), a.as_ptr());
+    let missing: Option<ArcRefA<Chunk, str>> = interner.get("xyz");
+    assert!(missing.is_none());
+
+    let token: ArcRefC<String, str> =
+        ArcRefC::new(String::from("defghi")).map(|text| text.as_str());
+    let interned = interner.intern_ref(token);
+    assert_eq!(interned.as_ptr(), b.as_ptr());
+    assert_eq!(interner.len(), 3);
+
+    drop(interner);
+    assert_eq!(&*a, "abc");
+}
+
+#[test]
+fn interner_concurrent() {
+    let interner = Interner::with_chunk_size(16);
+
+    let symbols: Vec<ArcRefA<Chunk, str>> = thread::scope(|scope| {
+        let handles: Vec<_> = (0..4)
+            .map(|_| {
+                let interner = &interner;
+                scope.spawn(move || {
+                    (0..100)
+                        .map(|index| interner.intern(&format!("sym{}", index % 10)))
+                        .collect::<Vec<ArcRefA<Chunk, str>>>()
+                })
+            })
+            .collect();
+        handles
+            .into_iter()
+            .flat_map(|handle| handle.join().unwrap())
+            .collect()
+    });
+
+    assert_eq!(interner.len(), 10);
+    assert_eq!(symbols[3], symbols[113]);
+    assert_ne!(symbols[3], symbols[4]);
+}
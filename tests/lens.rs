@@ -0,0 +1,86 @@
+use ownref::{ArcOwnedA, ArcRefA, BoxRefC, Lens, Segment};
+use std::collections::HashMap;
+
+#[derive(Clone)]
+enum Value {
+    Text(String),
+    Number(i64),
+}
+
+#[derive(Clone)]
+struct Model {
+    users: Vec<HashMap<&'static str, Value>>,
+}
+
+fn model() -> Model {
+    let alice = [
+        ("name", Value::Text("alice".into())),
+        ("age", Value::Number(7)),
+    ];
+    Model {
+        users: vec![alice.into_iter().collect()],
+    }
+}
+
+fn age_lens() -> Lens<Model, i64> {
+    Lens::field("users", |m: &Model| &m.users, |m| &mut m.users)
+        .then(Lens::index(0))
+        .then(Lens::key("age"))
+        .then(Lens::variant(
+            "Number",
+            |v: &Value| match v {
+                Value::Number(n) => Some(n),
+                _ => None,
+            },
+            |v| match v {
+                Value::Number(n) => Some(n),
+                _ => None,
+            },
+        ))
+}
+
+#[test]
+fn lens_debug() {
+    let lens = age_lens();
+    assert_eq!(format!("{:?}", lens), ".users[0][\"age\"]::Number");
+    assert_eq!(lens.path().len(), 4);
+    assert_eq!(lens.path()[1], Segment::Index(0));
+    assert_eq!(format!("{:?}", Lens::<Model, Model>::identity()), ".");
+}
+
+#[test]
+fn lens_apply() {
+    let lens = age_lens();
+
+    let age: ArcRefA<Model, i64> = lens.apply_arc_ref(ArcRefA::new(model())).unwrap();
+    assert_eq!(*age, 7);
+
+    // re-apply to a replaced owner
+    let mut replaced = model();
+    replaced.users[0].insert("age", Value::Number(9));
+    let age: ArcOwnedA<Model, &i64> = lens.apply_arc_owned(ArcOwnedA::new(replaced)).unwrap();
+    assert_eq!(**age, 9);
+
+    let mut age: BoxRefC<Model, i64> = lens.apply_box_ref(BoxRefC::new(model())).unwrap();
+    *age = 8;
+    let model = BoxRefC::into_owner(age);
+    assert!(matches!(model.users[0]["age"], Value::Number(8)));
+
+    // mismatched variant
+    let name_as_number = Lens::field("users", |m: &Model| &m.users, |m| &mut m.users)
+        .then(Lens::index(0))
+        .then(Lens::key("name"))
+        .then(Lens::variant(
+            "Number",
+            |v: &Value| match v {
+                Value::Number(n) => Some(n),
+                _ => None,
+            },
+            |v| match v {
+                Value::Number(n) => Some(n),
+                _ => None,
+            },
+        ));
+    assert!(name_as_number.get(&model).is_none());
+    assert!(matches!(&model.users[0]["name"], Value::Text(name) if name == "alice"));
+}
@@ -0,0 +1,44 @@
+use ownref::{Addr, ArcOwnedA, ArcRefA, ArcRefC, LocalArcRefA, LocalArcRefC};
+use std::{collections::HashSet, thread};
+
+#[test]
+fn local_arc_ref_flatten() {
+    let vec: ArcRefC<Vec<char>> = ArcRefC::new(vec!['a', 'b', 'a']);
+    let local = ArcRefC::into_local(vec.clone());
+    assert_eq!(LocalArcRefC::strong_count(&local), 2);
+
+    let items: Vec<LocalArcRefC<Vec<char>, char>> = local.flatten().collect();
+    assert_eq!(LocalArcRefC::strong_count(&items[0]), 2);
+    assert_eq!(LocalArcRefC::local_count(&items[0]), 3);
+    assert_eq!(items[0], items[2]);
+    assert!(items[0] < items[1]);
+
+    let set: HashSet<LocalArcRefC<Vec<char>, char>> = items.iter().cloned().collect();
+    assert!(set.contains(&'b'));
+    assert_eq!(set.len(), 2);
+    drop(set);
+
+    let item: ArcRefC<Vec<char>, char> = items[1].clone().into();
+    assert_eq!(ArcRefC::strong_count(&item), 3);
+    let handle = thread::spawn(move || *item);
+    assert_eq!(handle.join().unwrap(), 'b');
+
+    // the last local reference passes its Arc over
+    drop(items);
+    assert_eq!(ArcRefC::strong_count(&vec), 1);
+}
+
+#[test]
+fn local_arc_ref_a() {
+    let local: LocalArcRefA<Vec<char>> = ArcOwnedA::into_local(ArcOwnedA::new(vec!['a', 'a']));
+
+    let items: Vec<LocalArcRefA<Vec<char>, char>> =
+        local.clone().flat_map(|vec| vec.iter()).collect();
+    assert_ne!(items[0], items[1]);
+    let set: HashSet<LocalArcRefA<Vec<char>, char>> = items.into_iter().collect();
+    assert!(set.contains(Addr::new(&local[1])));
+
+    let first = local.filter_map(|vec| vec.first()).unwrap();
+    let first = LocalArcRefA::into_arc_ref(first);
+    assert_eq!(ArcRefA::strong_count(&first), 2);
+}
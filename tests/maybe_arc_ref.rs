@@ -0,0 +1,83 @@
+use ownref::{ArcRefA, ArcRefC, MaybeArcRefA, MaybeArcRefC};
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    cell::Cell,
+    collections::HashSet,
+    mem,
+};
+
+static NAMES: [&str; 2] = ["alice", "bob"];
+
+#[test]
+fn maybe_arc_ref_c() {
+    let loaded: MaybeArcRefC<Vec<String>, str> =
+        MaybeArcRefC::new(vec!["bob".to_string()]).map(|names| names[0].as_str());
+    let literal: MaybeArcRefC<Vec<String>, str> = MaybeArcRefC::from_static(NAMES[1]);
+
+    assert!(!MaybeArcRefC::is_static(&loaded));
+    assert!(MaybeArcRefC::is_static(&literal));
+    assert_eq!(MaybeArcRefC::strong_count(&loaded), Some(1));
+    assert_eq!(MaybeArcRefC::strong_count(&literal), None);
+    assert_eq!(loaded, literal);
+
+    let set: HashSet<_> = [loaded.clone(), literal.clone()].into_iter().collect();
+    assert_eq!(set.len(), 1);
+
+    assert!(MaybeArcRefC::into_arc_ref(loaded).is_ok());
+    assert_eq!(MaybeArcRefC::into_arc_ref(literal).unwrap_err(), "bob");
+}
+
+#[test]
+fn maybe_arc_ref_a() {
+    let first = MaybeArcRefA::<(), _>::from_static(&NAMES).map(|names| &names[0]);
+    let second = first.clone();
+    let other = MaybeArcRefA::<(), _>::from_static(&NAMES).map(|names| &names[1]);
+    assert_eq!(first, second);
+    assert_ne!(first, other);
+
+    let owned: MaybeArcRefA<[char; 2], char> =
+        ArcRefA::new(['a', 'b']).map(|array| &array[1]).into();
+    assert_eq!(*owned, 'b');
+    assert_eq!(MaybeArcRefA::owner(&owned), Some(&['a', 'b']));
+
+    assert_eq!(
+        mem::size_of::<MaybeArcRefA<[char; 2], char>>(),
+        mem::size_of::<ArcRefA<[char; 2], char>>()
+    );
+}
+
+#[test]
+fn arc_ref_from_static_without_allocation() {
+    let before = allocations();
+    let literal: MaybeArcRefC<String, str> = ArcRefC::from_static("literal");
+    let copy = literal.clone().map(|text| &text[1..]);
+    assert_eq!(allocations(), before);
+
+    assert!(MaybeArcRefC::is_static(&copy));
+    assert_eq!(&*copy, "iteral");
+}
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+/// Count the allocations on each thread, so that parallel tests do not interfere.
+struct CountingAlloc;
+
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let _ = ALLOCATIONS.try_with(|count| count.set(count.get() + 1));
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAlloc = CountingAlloc;
+
+fn allocations() -> usize {
+    ALLOCATIONS.with(Cell::get)
+}
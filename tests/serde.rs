@@ -0,0 +1,51 @@
+#![cfg(feature = "serde")]
+
+use ownref::{ArcOwnedC, ArcRefC, BoxOwnedA, BoxRefC};
+use serde::{Deserialize, Serialize};
+use std::ops::Range;
+
+#[derive(Debug, Serialize, Deserialize)]
+struct Config<'a> {
+    name: &'a str,
+    tags: Vec<&'a str>,
+}
+
+#[test]
+fn serialize() {
+    let owner = ArcRefC::new(vec![(1, "a"), (2, "b")]);
+    let second = owner.clone().map(|vec| &vec[1]);
+    assert_eq!(
+        serde_json::to_string(&owner).unwrap(),
+        r#"[[1,"a"],[2,"b"]]"#
+    );
+    assert_eq!(serde_json::to_string(&second).unwrap(), r#"[2,"b"]"#);
+
+    let mut text = BoxRefC::new(String::from("text"));
+    text.push('!');
+    assert_eq!(serde_json::to_string(&text).unwrap(), r#""text!""#);
+}
+
+#[test]
+fn deserialize_with() {
+    let text = String::from(r#"{"name": "server", "tags": ["a", "b"]}"#);
+    let config: ArcOwnedC<String, Config> =
+        ArcOwnedC::deserialize_with(text, |text| serde_json::from_str(text)).unwrap();
+    assert_eq!(config.name, "server");
+    assert_eq!(config.tags, ["a", "b"]);
+
+    // the data borrows from the owner
+    let Range { start, end } = ArcOwnedC::owner(&config).as_bytes().as_ptr_range();
+    assert!((start..end).contains(&config.name.as_ptr()));
+
+    let name: ArcOwnedC<String, &str> = config.map(|config| config.name);
+    assert_eq!(*name, "server");
+
+    let bytes = br#"{"name": "client", "tags": []}"#.to_vec();
+    let config: BoxOwnedA<Vec<u8>, Config> =
+        BoxOwnedA::deserialize_with(bytes, |bytes| serde_json::from_slice(bytes)).unwrap();
+    assert_eq!(config.name, "client");
+
+    let result: Result<BoxOwnedA<Vec<u8>, Config>, _> =
+        BoxOwnedA::deserialize_with(b"{}".to_vec(), |bytes| serde_json::from_slice(bytes));
+    assert!(result.is_err());
+}
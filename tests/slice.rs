@@ -0,0 +1,96 @@
+use ownref::{ArcChunkMutA, ArcChunkMutC, ArcRefA, ArcRefC, BoxRefA, BoxRefC};
+
+#[test]
+fn slice_access() {
+    let slice: ArcRefC<Vec<u32>, [u32]> =
+        ArcRefC::new(vec![1, 3, 5, 7, 9]).map(|vec| vec.as_slice());
+
+    let second: ArcRefC<Vec<u32>, u32> = ArcRefC::get(&slice, 1).unwrap();
+    assert_eq!(*second, 3);
+    assert_eq!(*ArcRefC::get(&slice, 1..3).unwrap(), [3, 5]);
+    assert!(ArcRefC::get(&slice, 5).is_none());
+    assert!(ArcRefC::get(&slice, 4..6).is_none());
+
+    assert_eq!(*ArcRefC::first(&slice).unwrap(), 1);
+    assert_eq!(*ArcRefC::last(&slice).unwrap(), 9);
+    let empty = ArcRefC::slice(&slice, 5..);
+    assert!(ArcRefC::first(&empty).is_none());
+    assert!(ArcRefC::last(&empty).is_none());
+
+    let found = ArcRefC::binary_search_by(&slice, |value| value.cmp(&7)).unwrap();
+    assert_eq!(*found, 7);
+    assert_eq!(
+        ArcRefC::binary_search_by(&slice, |value| value.cmp(&4)),
+        Err(2)
+    );
+    assert_eq!(ArcRefC::strong_count(&slice), 4);
+}
+
+#[test]
+fn slice_iter() {
+    let slice: ArcRefA<Vec<u32>, [u32]> =
+        ArcRefA::new(vec![1, 0, 2, 3, 0, 4]).map(|vec| vec.as_slice());
+
+    let items: Vec<(usize, u32)> = ArcRefA::enumerate(slice.clone())
+        .map(|(index, value)| (index, *value))
+        .collect();
+    assert_eq!(items, [(0, 1), (1, 0), (2, 2), (3, 3), (4, 0), (5, 4)]);
+
+    let chunks: Vec<ArcRefA<Vec<u32>, [u32]>> = ArcRefA::chunks(slice.clone(), 4).collect();
+    assert_eq!(*chunks[0], [1, 0, 2, 3]);
+    assert_eq!(*chunks[1], [0, 4]);
+    assert_eq!(ArcRefA::strong_count(&slice), 3);
+
+    let windows: Vec<u32> = ArcRefA::windows(slice.clone(), 2)
+        .map(|window| window.iter().sum())
+        .collect();
+    assert_eq!(windows, [1, 2, 5, 3, 4]);
+
+    let parts: Vec<ArcRefA<Vec<u32>, [u32]>> =
+        ArcRefA::split_by(slice.clone(), |value| *value == 0).collect();
+    assert_eq!(parts.len(), 3);
+    assert_eq!(*parts[1], [2, 3]);
+    assert_eq!(parts[2], ArcRefA::slice(&slice, 5..));
+}
+
+#[test]
+fn slice_sort() {
+    let mut slice: BoxRefC<Vec<u32>, [u32]> =
+        BoxRefC::new(vec![5, 1, 4, 2, 3]).map(|vec| &mut vec[1..]);
+
+    BoxRefC::sort(&mut slice);
+    assert_eq!(*slice, [1, 2, 3, 4]);
+    BoxRefC::reverse(&mut slice);
+    assert_eq!(*slice, [4, 3, 2, 1]);
+    BoxRefC::sort_by(&mut slice, |lhs, rhs| (lhs % 2).cmp(&(rhs % 2)));
+    assert_eq!(*slice, [4, 2, 3, 1]);
+    assert_eq!(*BoxRefC::into_box(slice), [5, 4, 2, 3, 1]);
+}
+
+#[test]
+fn slice_chunks_mut() {
+    // the array is moved when the owner is moved into Arc
+    let data: BoxRefC<[u8; 5], [u8]> = BoxRefC::new([0; 5]).map(|array| &mut array[1..]);
+    let mut chunks: Vec<ArcChunkMutC<[u8; 5], [u8]>> = BoxRefC::chunks_mut(data, 3).collect();
+    assert_eq!(chunks.len(), 2);
+    assert_eq!(ArcChunkMutC::strong_count(&chunks[0]), 2);
+
+    chunks[0].copy_from_slice(&[1, 2, 3]);
+    chunks[1].copy_from_slice(&[4]);
+    assert_eq!(*chunks[0], [1, 2, 3]);
+
+    let last = chunks.pop().unwrap();
+    let last = ArcChunkMutC::try_into_arc(last).unwrap_err();
+    drop(chunks);
+    let owner = ArcChunkMutC::try_into_arc(last).unwrap();
+    assert_eq!(*owner, [0, 1, 2, 3, 4]);
+
+    let data: BoxRefA<Vec<u8>, [u8]> = BoxRefA::new(vec![0; 4]).map(|vec| &mut vec[..]);
+    let mut chunks: Vec<ArcChunkMutA<Vec<u8>, [u8]>> = BoxRefA::chunks_mut(data, 2).collect();
+    chunks.iter_mut().for_each(|chunk| chunk[0] = 1);
+    assert_ne!(chunks[0], chunks[1]);
+    let last = chunks.pop().unwrap();
+    let last = ArcChunkMutA::try_unwrap_owner(last).unwrap_err();
+    drop(chunks);
+    assert_eq!(ArcChunkMutA::try_unwrap_owner(last).unwrap(), [1, 0, 1, 0]);
+}
@@ -0,0 +1,76 @@
+use ownref::{ArcOwnedC, ArcRefA, ArcRefC, BoxRefC};
+
+fn as_strs<'a>(refs: &'a [ArcRefC<String, str>]) -> Vec<&'a str> {
+    refs.iter().map(|item| &**item).collect()
+}
+
+#[test]
+fn arc_ref_text() {
+    let text: ArcRefC<String, str> =
+        ArcRefC::new(String::from(" key=value \nnext line\n")).map(|text| text.as_str());
+
+    let lines: Vec<_> = ArcRefC::lines(text.clone()).collect();
+    assert_eq!(as_strs(&lines), [" key=value ", "next line"]);
+    assert_eq!(ArcRefC::strong_count(&text), 3);
+
+    let (key, value) = ArcRefC::split_once(ArcRefC::trim(lines[0].clone()), '=').unwrap();
+    assert_eq!((&*key, &*value), ("key", "value"));
+
+    let words: Vec<_> = ArcRefC::split_whitespace(text.clone()).collect();
+    assert_eq!(as_strs(&words), ["key=value", "next", "line"]);
+
+    let parts: Vec<_> = ArcRefC::split(lines[1].clone(), |ch: char| ch == 'e').collect();
+    assert_eq!(as_strs(&parts), ["n", "xt lin", ""]);
+
+    let parts: Vec<_> = ArcRefC::split(text.clone(), ['=', '\n']).collect();
+    assert_eq!(parts.len(), 4);
+
+    assert_eq!(ArcRefC::matches(text.clone(), "e").count(), 4);
+    assert_eq!(&*ArcRefC::trim_start(lines[0].clone()), "key=value ");
+    assert_eq!(&*ArcRefC::trim_end(lines[0].clone()), " key=value");
+}
+
+#[test]
+fn arc_ref_slice_chars() {
+    let text: ArcRefA<String, str> = ArcRefA::new(String::from("añb€c")).map(|text| text.as_str());
+    assert_eq!(&*ArcRefA::slice_chars(&text, 1..4), "ñb€");
+    assert_eq!(&*ArcRefA::slice_chars(&text, 3..), "€c");
+    assert_eq!(&*ArcRefA::slice_chars(&text, ..=1), "añ");
+    assert_eq!(&*ArcRefA::slice_chars(&text, 5..), "");
+
+    let result = std::panic::catch_unwind(|| ArcRefA::slice_chars(&text, 2..7));
+    assert!(result.is_err());
+}
+
+#[test]
+fn box_ref_text() {
+    let text: BoxRefC<String, str> =
+        BoxRefC::new(String::from("  héllo  ")).map(|text| text.as_mut_str());
+    let mut text = BoxRefC::trim(text);
+    assert_eq!(&*text, "héllo");
+
+    text.make_ascii_uppercase();
+    let text = BoxRefC::slice_chars(text, 1..);
+    assert_eq!(&*text, "éLLO");
+    assert_eq!(*BoxRefC::into_box(text), "  HéLLO  ");
+
+    let blank = BoxRefC::trim(BoxRefC::new(String::from("   ")).map(|text| text.as_mut_str()));
+    assert_eq!(&*blank, "");
+}
+
+#[test]
+fn arc_owned_text() {
+    let text: ArcOwnedC<String, &str> =
+        ArcOwnedC::new(String::from("a, b,c")).map(|text| text.as_str());
+    let items: Vec<_> = ArcOwnedC::split(text.clone(), ",")
+        .map(ArcOwnedC::trim)
+        .collect();
+    assert_eq!(
+        items.iter().map(|item| **item).collect::<Vec<_>>(),
+        ["a", "b", "c"]
+    );
+
+    let (lhs, rhs) = ArcOwnedC::split_once(text.clone(), ", ").unwrap();
+    assert_eq!((*lhs, *rhs), ("a", "b,c"));
+    assert_eq!(*ArcOwnedC::slice_chars(&text, 3..), "b,c");
+}
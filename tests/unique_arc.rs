@@ -0,0 +1,45 @@
+use ownref::{ArcOwnedC, ArcRefC, BoxOwnedC, BoxRefC, UniqueArc};
+use std::sync::Arc;
+
+#[test]
+fn unique_arc_freeze() {
+    let mut ref_: BoxRefC<UniqueArc<[u64; 4]>, u64> =
+        BoxRefC::new(UniqueArc::new([1, 2, 3, 4])).map(|owner| &mut owner[1]);
+    *ref_ += 10;
+    let addr = &*ref_ as *const u64;
+
+    let ref_: ArcRefC<[u64; 4], u64> = BoxRefC::freeze(ref_);
+    assert_eq!(*ref_, 12);
+    assert_eq!(&*ref_ as *const u64, addr);
+    assert_eq!(*ArcRefC::owner(&ref_), [1, 12, 3, 4]);
+}
+
+#[test]
+#[should_panic]
+fn unique_arc_freeze_handle() {
+    let ref_: BoxRefC<UniqueArc<[u64; 4]>> = BoxRefC::new(UniqueArc::new([1, 2, 3, 4]));
+    let _ = BoxRefC::freeze(ref_);
+}
+
+#[test]
+fn unique_arc_freeze_owned() {
+    let own: BoxOwnedC<UniqueArc<Vec<u8>>, &mut [u8]> =
+        BoxOwnedC::new(UniqueArc::new(vec![1, 2, 3])).map(|owner| &mut owner[1..]);
+    let addr = own.as_ptr();
+
+    let own: ArcOwnedC<Vec<u8>, &mut [u8]> = unsafe { BoxOwnedC::freeze(own) };
+    assert_eq!(**own, [2, 3]);
+    assert_eq!(own.as_ptr(), addr);
+}
+
+#[test]
+fn unique_arc_try_from_arc() {
+    let arc = Arc::new(5);
+    let cloned = arc.clone();
+    let arc = UniqueArc::try_from_arc(arc).unwrap_err();
+    drop(cloned);
+
+    let mut unique = UniqueArc::try_from_arc(arc).unwrap();
+    *unique += 1;
+    assert_eq!(UniqueArc::into_inner(unique), 6);
+}
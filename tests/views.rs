@@ -0,0 +1,63 @@
+use indexmap::IndexMap;
+use ownref::{
+    Addr, ArcOwnedA, ArcOwnedC, ArcOwnedViewA, ArcOwnedViewC, ArcRefA, ArcRefC, ArcRefViewA,
+    ArcRefViewC,
+};
+use std::collections::HashSet;
+
+#[test]
+fn arc_ref_views() {
+    let vec: ArcRefC<Vec<u32>> = ArcRefC::new((0..100).collect());
+
+    let kept: Vec<ArcRefC<Vec<u32>, u32>> = ArcRefC::iter_views(&vec)
+        .filter(|value| **value % 50 == 7)
+        .map(|view| ArcRefViewC::to_arc_ref(&view))
+        .collect();
+    assert_eq!(*kept[1], 57);
+    assert_eq!(ArcRefC::strong_count(&vec), 3);
+
+    let views: Vec<ArcRefViewC<Vec<u32>, u32>> = ArcRefC::iter_views(&vec).collect();
+    assert_eq!(ArcRefC::strong_count(&vec), 3);
+    assert!(views[1] < views[2]);
+    assert_eq!(views.iter().max().map(|view| **view), Some(99));
+    assert!(std::ptr::eq(ArcRefViewC::owner(&views[0]), &*vec));
+
+    let set: HashSet<ArcRefViewC<Vec<u32>, u32>> = views.into_iter().collect();
+    assert!(set.contains(&42));
+}
+
+#[test]
+fn arc_ref_views_by_address() {
+    let array: ArcRefA<[char; 2]> = ArcRefA::new(['a', 'a']);
+
+    let views: Vec<ArcRefViewA<[char; 2], char>> = ArcRefA::iter_views(&array).collect();
+    assert_ne!(views[0], views[1]);
+    assert_eq!(views[0], views[0].map(|value| value));
+
+    let set: HashSet<ArcRefViewA<[char; 2], char>> = views.iter().copied().collect();
+    assert!(set.contains(Addr::new(&array[1])));
+
+    let first: ArcRefA<[char; 2], char> = ArcRefViewA::to_arc_ref(&views[0]);
+    assert_eq!(first, array.clone().map(|array| &array[0]));
+}
+
+#[test]
+fn arc_owned_views() {
+    let map: IndexMap<_, _> = [('a', 1), ('b', 2), ('c', 1)].into_iter().collect();
+    let own: ArcOwnedC<IndexMap<char, usize>> = ArcOwnedC::new(map);
+
+    let kept: Vec<ArcOwnedC<_, (&char, &usize)>> = ArcOwnedC::iter_views(&own)
+        .filter(|view| *view.1 == 1)
+        .map(ArcOwnedViewC::into_arc_owned)
+        .collect();
+    assert_eq!(kept.len(), 2);
+    assert_eq!(*kept[1], (&'c', &1));
+    assert_eq!(ArcOwnedC::strong_count(&own), 3);
+
+    let values: ArcOwnedA<_, &[u32]> = ArcOwnedA::new(vec![5, 5]).map(|vec| vec.as_slice());
+
+    let views: Vec<ArcOwnedViewA<Vec<u32>, &u32>> = ArcOwnedA::iter_views(&values).collect();
+    assert_ne!(views[0], views[1]);
+    assert!(views[0] < views[1]);
+    assert_eq!(ArcOwnedA::strong_count(&values), 1);
+}
@@ -0,0 +1,137 @@
+use ownref::{ArcOwnedC, ArcRefC, Waitable};
+use std::{
+    future::Future,
+    pin::pin,
+    sync::Arc,
+    task::{Context, Poll, Wake},
+    thread::{self, Thread},
+    time::Duration,
+};
+
+#[test]
+fn arc_ref_wait_unwrap_owner() {
+    let owner = ArcRefC::new(Waitable::new(vec![1, 2, 3]));
+    let first = ArcRefC::into_lease(owner.clone().map(|vec| &vec[0]));
+
+    let owner = ArcRefC::wait_unwrap_owner(owner, Duration::from_millis(20)).unwrap_err();
+
+    let handle = thread::spawn(move || {
+        thread::sleep(Duration::from_millis(50));
+        assert_eq!(**first, 1);
+    });
+    let vec = ArcRefC::wait_unwrap_owner(owner, Duration::from_secs(60)).unwrap();
+    assert_eq!(vec, [1, 2, 3]);
+    handle.join().unwrap();
+}
+
+#[test]
+fn arc_ref_wait_unwrap_owner_plain() {
+    let owner = ArcRefC::new(Waitable::new(vec![1, 2, 3]));
+    let first: ArcRefC<Waitable<Vec<u32>>, u32> = owner.clone().map(|vec| &vec[0]);
+
+    // the plain references do not notify, and the waiter notices the drop by re-checking
+    let handle = thread::spawn(move || {
+        thread::sleep(Duration::from_millis(50));
+        drop(first);
+    });
+    let vec = ArcRefC::wait_unwrap_owner(owner, Duration::from_secs(60)).unwrap();
+    assert_eq!(vec, [1, 2, 3]);
+    handle.join().unwrap();
+}
+
+#[test]
+fn arc_ref_wait_unwrap_owner_timeout() {
+    let owner = ArcRefC::new(Waitable::new(vec![1, 2, 3]));
+    let first = owner.clone().map(|vec| &vec[0]);
+
+    let owner = ArcRefC::wait_unwrap_owner(owner, Duration::from_millis(50)).unwrap_err();
+    assert_eq!(*first, 1);
+    drop(first);
+
+    let vec = ArcRefC::wait_unwrap_owner(owner, Duration::ZERO).unwrap();
+    assert_eq!(vec, [1, 2, 3]);
+}
+
+#[test]
+fn arc_owned_wait_unwrap_owner() {
+    let owner = ArcOwnedC::new(Waitable::new(String::from("owner")));
+    let views: Vec<_> = owner
+        .clone()
+        .flat_map(|text| text.chars())
+        .map(ArcOwnedC::into_lease)
+        .collect();
+
+    let handle = thread::spawn(move || {
+        thread::sleep(Duration::from_millis(50));
+        drop(views);
+    });
+    let text = ArcOwnedC::wait_unwrap_owner(owner, Duration::from_secs(60)).unwrap();
+    assert_eq!(text, "owner");
+    handle.join().unwrap();
+}
+
+#[test]
+fn arc_ref_unwrap_owner_when_unique() {
+    let owner = ArcRefC::new(Waitable::new(vec![1, 2, 3]));
+    let last = ArcRefC::into_lease(owner.clone().map(|vec| &vec[2]));
+
+    let handle = thread::spawn(move || {
+        thread::sleep(Duration::from_millis(50));
+        assert_eq!(**last, 3);
+    });
+    let vec = block_on(ArcRefC::unwrap_owner_when_unique(owner));
+    assert_eq!(vec, [1, 2, 3]);
+    handle.join().unwrap();
+}
+
+#[test]
+fn arc_ref_unwrap_owner_when_unique_plain() {
+    let owner = ArcRefC::new(Waitable::new(vec![1, 2, 3]));
+    let views: Vec<_> = owner.clone().flat_map(|vec| vec.iter()).collect();
+
+    let handle = thread::spawn(move || {
+        thread::sleep(Duration::from_millis(50));
+        drop(views);
+    });
+    let vec = block_on(ArcRefC::unwrap_owner_when_unique(owner));
+    assert_eq!(vec, [1, 2, 3]);
+    handle.join().unwrap();
+}
+
+#[test]
+fn arc_owned_unwrap_owner_when_unique() {
+    let owner = ArcOwnedC::new(Waitable::new(String::from("owner")));
+    let len = ArcOwnedC::into_lease(owner.clone().map(|text| text.len()));
+
+    let handle = thread::spawn(move || {
+        thread::sleep(Duration::from_millis(50));
+        assert_eq!(**len, 5);
+    });
+    let text = block_on(ArcOwnedC::unwrap_owner_when_unique(owner));
+    assert_eq!(text, "owner");
+    handle.join().unwrap();
+}
+
+struct ThreadWaker(Thread);
+
+impl Wake for ThreadWaker {
+    fn wake(self: Arc<Self>) {
+        self.0.unpark();
+    }
+}
+
+fn block_on<F>(future: F) -> F::Output
+where
+    F: Future,
+{
+    let waker = Arc::new(ThreadWaker(thread::current())).into();
+    let mut cx = Context::from_waker(&waker);
+    let mut future = pin!(future);
+
+    loop {
+        match future.as_mut().poll(&mut cx) {
+            Poll::Ready(output) => return output,
+            Poll::Pending => thread::park(),
+        }
+    }
+}